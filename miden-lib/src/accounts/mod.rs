@@ -1,4 +1,57 @@
-use super::{auth::AuthScheme, transaction::TransactionKernel, Library, MidenLib};
+use miden_objects::{
+    accounts::{Account, AccountType},
+    assets::FungibleAsset,
+    notes::{Note, NoteType},
+    utils::string::*,
+    AccountError, Hasher, Word,
+};
+
+use super::{
+    auth::AuthScheme, notes::create_p2id_note_with_serial_num, transaction::TransactionKernel,
+    Library, MidenLib,
+};
 
 pub mod faucets;
 pub mod wallets;
+
+// ACCOUNT ONBOARDING
+// ================================================================================================
+
+/// Creates a new basic wallet account together with a note which funds it with `amount` units of
+/// the fungible asset issued by `faucet`.
+///
+/// The wallet is created as in [wallets::create_basic_wallet()]. The funding note is a P2ID note
+/// sent by the faucet to the new account, i.e., the note which the faucet has to create (e.g., via
+/// its `distribute` procedure) for the account to receive the funds. The serial number of the
+/// note is derived from `init_seed`, so the note can be recomputed from the same inputs.
+///
+/// Returns the new account, the seed of the account, and the funding note.
+///
+/// # Errors
+/// Returns an error if:
+/// - The account cannot be created from the provided inputs.
+/// - The faucet is not a fungible faucet or the amount is not a valid amount of a fungible asset.
+pub fn create_and_fund_account(
+    init_seed: [u8; 32],
+    auth_scheme: AuthScheme,
+    account_type: AccountType,
+    faucet: &Account,
+    amount: u64,
+) -> Result<(Account, Word, Note), AccountError> {
+    let (account, account_seed) =
+        wallets::create_basic_wallet(init_seed, auth_scheme, account_type)?;
+
+    let asset = FungibleAsset::new(faucet.id(), amount)
+        .map_err(|err| AccountError::FundingNoteInvalid(err.to_string()))?;
+    let serial_num = Hasher::hash(&init_seed).into();
+    let note = create_p2id_note_with_serial_num(
+        faucet.id(),
+        account.id(),
+        &[asset.into()],
+        NoteType::OffChain,
+        serial_num,
+    )
+    .map_err(|err| AccountError::FundingNoteInvalid(err.to_string()))?;
+
+    Ok((account, account_seed, note))
+}
@@ -12,6 +12,9 @@ use crate::utils::{string::*, vec};
 // BASIC WALLET
 // ================================================================================================
 
+/// Maximum number of authentication schemes a basic wallet can be created with.
+pub const MAX_WALLET_AUTH_SCHEMES: usize = 4;
+
 /// Creates a new account with basic wallet interface and the specified authentication scheme.
 /// Basic wallets can be specified to have either mutable or immutable code.
 ///
@@ -28,16 +31,56 @@ pub fn create_basic_wallet(
     auth_scheme: AuthScheme,
     account_type: AccountType,
 ) -> Result<(Account, Word), AccountError> {
-    if matches!(account_type, AccountType::FungibleFaucet | AccountType::NonFungibleFaucet) {
+    create_basic_wallet_with_auth_schemes(init_seed, vec![auth_scheme], account_type)
+}
+
+/// Creates a new account with basic wallet interface which can be authenticated by any of the
+/// specified authentication schemes.
+///
+/// Public key information for each of the schemes is stored in the account storage in consecutive
+/// slots starting at slot 0, in the order in which the schemes are provided. When more than one
+/// scheme is provided, the account exposes the `auth_tx_rpo_falcon512_multi` authentication
+/// procedure which expects the index of the key used to sign the transaction on the stack.
+///
+/// # Errors
+/// Returns an error if:
+/// - The account type is a faucet account type.
+/// - No authentication schemes, or more than [MAX_WALLET_AUTH_SCHEMES] schemes are provided.
+pub fn create_basic_wallet_with_auth_schemes(
+    init_seed: [u8; 32],
+    auth_schemes: Vec<AuthScheme>,
+    account_type: AccountType,
+) -> Result<(Account, Word), AccountError> {
+    if account_type.is_faucet() {
         return Err(AccountError::AccountIdInvalidFieldElement(
             "Basic wallet accounts cannot have a faucet account type".to_string(),
         ));
     }
 
-    let (auth_scheme_procedure, storage_slot_0_data): (&str, Word) = match auth_scheme {
-        AuthScheme::RpoFalcon512 { pub_key } => ("basic::auth_tx_rpo_falcon512", pub_key.into()),
+    if auth_schemes.is_empty() || auth_schemes.len() > MAX_WALLET_AUTH_SCHEMES {
+        return Err(AccountError::InvalidAuthSchemeCount {
+            max: MAX_WALLET_AUTH_SCHEMES,
+            actual: auth_schemes.len(),
+        });
+    }
+
+    let auth_scheme_procedure = if auth_schemes.len() == 1 {
+        "basic::auth_tx_rpo_falcon512"
+    } else {
+        "basic::auth_tx_rpo_falcon512_multi"
     };
 
+    let storage_slots = auth_schemes
+        .into_iter()
+        .enumerate()
+        .map(|(idx, auth_scheme)| {
+            let key: Word = match auth_scheme {
+                AuthScheme::RpoFalcon512 { pub_key } => pub_key.into(),
+            };
+            (idx as u8, (StorageSlotType::Value { value_arity: 0 }, key))
+        })
+        .collect();
+
     let account_code_string: String = format!(
         "
     use.miden::contracts::wallets::basic->basic_wallet
@@ -56,10 +99,7 @@ pub fn create_basic_wallet(
     let account_assembler = TransactionKernel::assembler();
     let account_code = AccountCode::new(account_code_ast.clone(), &account_assembler)?;
 
-    let account_storage = AccountStorage::new(vec![(
-        0,
-        (StorageSlotType::Value { value_arity: 0 }, storage_slot_0_data),
-    )])?;
+    let account_storage = AccountStorage::new(storage_slots)?;
     let account_vault = AssetVault::new(&[]).expect("error on empty vault");
 
     let account_seed = AccountId::get_account_seed(
@@ -1,4 +1,9 @@
-use miden_objects::crypto::dsa::rpo_falcon512;
+use miden_objects::{
+    assembly::ProgramAst,
+    crypto::dsa::rpo_falcon512,
+    utils::{collections::*, format},
+    Digest, Word,
+};
 
 /// Defines authentication schemes available to standard and faucet accounts.
 pub enum AuthScheme {
@@ -8,3 +13,47 @@ pub enum AuthScheme {
     /// use RPO256. This makes the signature more efficient to verify in Miden VM.
     RpoFalcon512 { pub_key: rpo_falcon512::PublicKey },
 }
+
+/// Returns a transaction script which replaces the RPO Falcon512 public key of the executing
+/// account with `new_pub_key`.
+///
+/// The transaction is first authenticated with the current key of the account, and only then the
+/// new key is written into storage slot 0. This ordering is required: the authentication
+/// procedure reads the key from slot 0, so the transaction must be signed with the current key.
+/// The new key takes effect for the following transactions.
+///
+/// The script calls the account procedure with MAST root `set_item_root`, which must follow the
+/// `set_item` calling convention of the mock account: `[index, V, 0, 0, 0] -> [R', V]`. The
+/// account must also export `auth_tx_rpo_falcon512` of the basic authentication contract.
+pub fn rotate_auth_key_script(
+    set_item_root: Digest,
+    new_pub_key: rpo_falcon512::PublicKey,
+) -> ProgramAst {
+    let new_pub_key: Word = new_pub_key.into();
+    let new_pub_key = new_pub_key
+        .iter()
+        .map(|felt| format!("{}", felt.as_int()))
+        .collect::<Vec<_>>()
+        .join(".");
+    let script = format!(
+        "\
+        use.miden::contracts::auth::basic->auth_tx
+
+        begin
+            # authenticate the transaction with the current key
+            call.auth_tx::auth_tx_rpo_falcon512
+
+            push.{new_pub_key}
+            push.0
+            push.0 movdn.5 push.0 movdn.5 push.0 movdn.5
+            # => [0, NEW_PUB_KEY, 0, 0, 0]
+
+            call.{set_item_root}
+            # => [R', NEW_PUB_KEY]
+
+            dropw dropw
+        end
+        "
+    );
+    ProgramAst::parse(&script).expect("rotate auth key script is valid")
+}
@@ -9,7 +9,7 @@ use miden_objects::{
 };
 
 mod auth;
-pub use auth::AuthScheme;
+pub use auth::{rotate_auth_key_script, AuthScheme};
 
 pub mod accounts;
 pub mod notes;
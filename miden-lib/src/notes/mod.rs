@@ -1,11 +1,18 @@
 use miden_objects::{
-    accounts::AccountId, assets::Asset, crypto::rand::FeltRng, notes::Note, utils::collections::*,
+    accounts::AccountId,
+    assets::{Asset, FungibleAsset},
+    crypto::rand::FeltRng,
+    notes::{Note, NoteTag, NoteType},
+    utils::collections::*,
     Felt, NoteError, Word,
 };
 
-use self::utils::build_note_script;
+use self::well_known::{
+    p2id_note_script, p2idr_note_script, partial_claim_note_script, swap_note_script,
+};
 
 pub mod utils;
+pub mod well_known;
 
 // STANDARDIZED SCRIPTS
 // ================================================================================================
@@ -19,15 +26,14 @@ pub mod utils;
 /// is set to the target's account ID.
 ///
 /// # Errors
-/// Returns an error if deserialization or compilation of the `P2ID` script fails.
+/// Returns an error if the note cannot be constructed from the provided assets.
 pub fn create_p2id_note<R: FeltRng>(
     sender: AccountId,
     target: AccountId,
     assets: Vec<Asset>,
     mut rng: R,
 ) -> Result<Note, NoteError> {
-    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2ID.masb"));
-    let note_script = build_note_script(bytes)?;
+    let note_script = p2id_note_script();
 
     let inputs = [target.into()];
     let tag: Felt = target.into();
@@ -36,6 +42,31 @@ pub fn create_p2id_note<R: FeltRng>(
     Note::new(note_script, &inputs, &assets, serial_num, sender, tag)
 }
 
+/// Generates a P2ID note - pay to id note - with the specified serial number.
+///
+/// This is the same note as the one generated by [create_p2id_note()], except that the caller
+/// provides the serial number of the note, and the note's tag is built from the target's account
+/// ID and the note type as described in [NoteTag::for_account()].
+///
+/// # Errors
+/// Returns an error if:
+/// - The note cannot be constructed from the provided assets.
+/// - The prefix of the target's account ID is reserved and cannot be used by a note tag.
+pub fn create_p2id_note_with_serial_num(
+    sender: AccountId,
+    target: AccountId,
+    assets: &[Asset],
+    note_type: NoteType,
+    serial_num: Word,
+) -> Result<Note, NoteError> {
+    let note_script = p2id_note_script();
+
+    let inputs = [target.into()];
+    let tag = NoteTag::for_account(target, note_type)?.inner();
+
+    Note::new(note_script, &inputs, assets, serial_num, sender, tag)
+}
+
 /// Generates a P2IDR note - pay to id with recall after a certain block height.
 ///
 /// This script enables the transfer of assets from the sender `sender` account to the `target`
@@ -47,7 +78,7 @@ pub fn create_p2id_note<R: FeltRng>(
 /// is set to the target's account ID.
 ///
 /// # Errors
-/// Returns an error if deserialization or compilation of the `P2IDR` script fails.
+/// Returns an error if the note cannot be constructed from the provided assets.
 pub fn create_p2idr_note<R: FeltRng>(
     sender: AccountId,
     target: AccountId,
@@ -55,8 +86,7 @@ pub fn create_p2idr_note<R: FeltRng>(
     recall_height: u32,
     mut rng: R,
 ) -> Result<Note, NoteError> {
-    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2IDR.masb"));
-    let note_script = build_note_script(bytes)?;
+    let note_script = p2idr_note_script();
 
     let inputs = [target.into(), recall_height.into()];
     let tag: Felt = target.into();
@@ -72,15 +102,14 @@ pub fn create_p2idr_note<R: FeltRng>(
 /// new P2ID note with `sender` as target, containing the `requested_asset`.
 ///
 /// # Errors
-/// Returns an error if deserialization or compilation of the `SWAP` script fails.
+/// Returns an error if the note cannot be constructed from the provided assets.
 pub fn create_swap_note<R: FeltRng>(
     sender: AccountId,
     offered_asset: Asset,
     requested_asset: Asset,
     mut rng: R,
 ) -> Result<(Note, Word), NoteError> {
-    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/SWAP.masb"));
-    let note_script = build_note_script(bytes)?;
+    let note_script = swap_note_script();
 
     let payback_serial_num = rng.draw_word();
     let payback_recipient = utils::build_p2id_recipient(sender, payback_serial_num)?;
@@ -105,3 +134,48 @@ pub fn create_swap_note<R: FeltRng>(
 
     Ok((note, payback_serial_num))
 }
+
+/// Generates a PARTIAL_CLAIM note - claim of a part of a fungible asset.
+///
+/// This script enables any account willing to consume the note to claim `claim_amount` units of
+/// the fungible `asset` carried by the note. The remainder of the asset is sent back to the
+/// `sender` in a new P2ID note; no such note is created if the whole asset is claimed.
+///
+/// The passed-in `rng` is used to generate the serial numbers of the note and of the change note.
+/// The serial number of the change note is returned together with the note.
+///
+/// # Errors
+/// Returns an error if:
+/// - The claim amount is greater than the amount of the asset.
+/// - The note cannot be constructed from the provided asset.
+pub fn create_partial_claim_note<R: FeltRng>(
+    sender: AccountId,
+    asset: FungibleAsset,
+    claim_amount: u64,
+    mut rng: R,
+) -> Result<(Note, Word), NoteError> {
+    if claim_amount > asset.amount() {
+        return Err(NoteError::ClaimAmountTooLarge { claim_amount, amount: asset.amount() });
+    }
+
+    let note_script = partial_claim_note_script();
+
+    let change_serial_num = rng.draw_word();
+    let change_recipient = utils::build_p2id_recipient(sender, change_serial_num)?;
+
+    let inputs = [
+        change_recipient[0],
+        change_recipient[1],
+        change_recipient[2],
+        change_recipient[3],
+        Felt::new(claim_amount),
+        sender.into(),
+    ];
+
+    let tag: Felt = Felt::new(0);
+    let serial_num = rng.draw_word();
+
+    let note = Note::new(note_script, &inputs, &[asset.into()], serial_num, sender, tag)?;
+
+    Ok((note, change_serial_num))
+}
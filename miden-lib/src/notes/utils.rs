@@ -1,6 +1,9 @@
 use miden_objects::{
-    accounts::AccountId, assembly::ProgramAst, notes::NoteScript, Digest, Hasher, NoteError, Word,
-    ZERO,
+    accounts::AccountId,
+    assembly::ProgramAst,
+    notes::{build_recipient, NoteScript},
+    utils::{collections::*, format},
+    Digest, Hasher, NoteError, Word, ZERO,
 };
 
 use crate::transaction::TransactionKernel;
@@ -19,17 +22,114 @@ pub fn build_note_script(bytes: &[u8]) -> Result<NoteScript, NoteError> {
 pub fn build_p2id_recipient(target: AccountId, serial_num: Word) -> Result<Digest, NoteError> {
     // TODO: add lazy_static initialization or compile-time optimization instead of re-generating
     // the script hash every time we call the SWAP script
-    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2ID.masb"));
-    let note_script = build_note_script(bytes)?;
+    let note_script = super::well_known::p2id_note_script();
+    let inputs_hash = Hasher::hash_elements(&[target.into(), ZERO, ZERO, ZERO]);
 
-    let script_hash = note_script.hash();
+    Ok(build_recipient(note_script.hash(), serial_num, inputs_hash))
+}
+
+/// Returns a note script which sets the storage slot `slot` of the consuming account
+/// to `value`.
+///
+/// The script calls the account procedure with MAST root `set_item_root`, which must follow the
+/// `set_item` calling convention of the mock account: `[index, V, 0, 0, 0] -> [R', V]`. Both
+/// returned words are dropped, so the script leaves the stack clean.
+pub fn set_storage_note_script(set_item_root: Digest, slot: u8, value: Word) -> ProgramAst {
+    let value = value
+        .iter()
+        .map(|felt| format!("{}", felt.as_int()))
+        .collect::<Vec<_>>()
+        .join(".");
+    let script = format!(
+        "\
+        begin
+            push.{value}
+            push.{slot}
+            push.0 movdn.5 push.0 movdn.5 push.0 movdn.5
+            # => [slot, VALUE, 0, 0, 0]
+
+            call.{set_item_root}
+            # => [R', VALUE]
+
+            dropw dropw
+        end
+        "
+    );
+    ProgramAst::parse(&script).expect("set storage note script is valid")
+}
+
+/// Returns a note script which can only be consumed once the block number of the reference block
+/// of the consuming transaction is greater than or equal to `unlock_block`.
+///
+/// The timelock check is prepended to the body of the `inner` script, which is executed only if
+/// the check passes. Since the unlock block is embedded into the script, it is committed to by the
+/// script root (and thus by the note ID). Local procedures and imports of `inner` are preserved.
+pub fn timelocked_note_script(unlock_block: u32, inner: ProgramAst) -> ProgramAst {
+    let guard = format!(
+        "\
+        const.ERR_NOTE_TIMELOCK_NOT_REACHED=0x00020045
+
+        begin
+            push.0 syscall.get_block_number
+            # => [current_block_number]
+
+            push.{unlock_block}
+            # => [unlock_block, current_block_number]
+
+            u32assert2 u32gte assert.err=ERR_NOTE_TIMELOCK_NOT_REACHED
+            # => []
+        end
+        "
+    );
+    let (_, guard_body) = ProgramAst::parse(&guard).expect("timelock guard is valid").into_parts();
+
+    let import_info = inner.import_info().clone();
+    let (local_procs, body) = inner.into_parts();
+    let body = guard_body.into_iter().chain(body).collect();
+
+    ProgramAst::new(body, local_procs)
+        .expect("timelocked note script is valid")
+        .with_import_info(import_info)
+}
+
+/// Returns a note script which can only be consumed by an account whose storage slot `slot` holds
+/// `expected`.
+///
+/// The storage check is prepended to the body of the `inner` script, which is executed only if
+/// the check passes. Since the slot index and the expected value are embedded into the script,
+/// they are committed to by the script root (and thus by the note ID). Local procedures and
+/// imports of `inner` are preserved.
+pub fn conditional_note_script(slot: u8, expected: Word, inner: ProgramAst) -> ProgramAst {
+    let expected = expected
+        .iter()
+        .map(|felt| format!("{}", felt.as_int()))
+        .collect::<Vec<_>>()
+        .join(".");
+    let guard = format!(
+        "\
+        const.ERR_NOTE_STORAGE_CONDITION_NOT_MET=0x00020049
+
+        begin
+            push.0.0.0.{slot} syscall.get_account_item
+            # => [VALUE]
+
+            push.{expected}
+            # => [EXPECTED, VALUE]
 
-    let serial_num_hash = Hasher::merge(&[serial_num.into(), Digest::default()]);
+            eqw assert.err=ERR_NOTE_STORAGE_CONDITION_NOT_MET dropw dropw
+            # => []
+        end
+        "
+    );
+    let (_, guard_body) = ProgramAst::parse(&guard)
+        .expect("storage condition guard is valid")
+        .into_parts();
 
-    let merge_script = Hasher::merge(&[serial_num_hash, script_hash]);
+    let import_info = inner.import_info().clone();
+    let (local_procs, body) = inner.into_parts();
+    let body = guard_body.into_iter().chain(body).collect();
 
-    Ok(Hasher::merge(&[
-        merge_script,
-        Hasher::hash_elements(&[target.into(), ZERO, ZERO, ZERO]),
-    ]))
+    ProgramAst::new(body, local_procs)
+        .expect("conditional note script is valid")
+        .with_import_info(import_info)
 }
@@ -0,0 +1,42 @@
+use miden_objects::notes::NoteScript;
+
+use super::utils::build_note_script;
+
+// WELL-KNOWN NOTE SCRIPTS
+// ================================================================================================
+
+/// Returns the script of the P2ID note - pay to id note.
+///
+/// Notes with this script can be consumed only by the account whose ID is specified as the note
+/// input. All assets of the note are added to the vault of the consuming account.
+pub fn p2id_note_script() -> NoteScript {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2ID.masb"));
+    build_note_script(bytes).expect("P2ID note script is valid")
+}
+
+/// Returns the script of the P2IDR note - pay to id with recall after a certain block height.
+///
+/// Notes with this script can be consumed by the target account at any time, and by the sender
+/// account once the recall height specified in the note inputs has been reached.
+pub fn p2idr_note_script() -> NoteScript {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2IDR.masb"));
+    build_note_script(bytes).expect("P2IDR note script is valid")
+}
+
+/// Returns the script of the PARTIAL_CLAIM note - claim of a part of a fungible asset.
+///
+/// The consuming account receives the claim amount specified in the note inputs and creates a
+/// note carrying the rest of the note asset back to the sender.
+pub fn partial_claim_note_script() -> NoteScript {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/PARTIAL_CLAIM.masb"));
+    build_note_script(bytes).expect("PARTIAL_CLAIM note script is valid")
+}
+
+/// Returns the script of the SWAP note - swap of assets between two accounts.
+///
+/// The consuming account receives the assets of the note and creates a P2ID note carrying the
+/// requested asset back to the sender.
+pub fn swap_note_script() -> NoteScript {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/SWAP.masb"));
+    build_note_script(bytes).expect("SWAP note script is valid")
+}
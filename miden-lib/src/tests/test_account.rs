@@ -93,7 +93,7 @@ pub fn test_set_code_succeeds() {
         "
     );
 
-    let (stack_inputs, advice_inputs) = executed_transaction.get_kernel_inputs();
+    let (stack_inputs, advice_inputs) = executed_transaction.get_kernel_inputs().unwrap();
     let host = MockHost::new(executed_transaction.initial_account().into(), advice_inputs);
     let process = run_within_host("", &code, stack_inputs, host, None).unwrap();
 
@@ -1,3 +1,4 @@
+use miden_objects::{NoteError, MAX_ASSETS_PER_NOTE};
 use mock::{
     mock::{notes::AssetPreservationStatus, transaction::mock_executed_tx},
     procedures::output_notes_data_procedure,
@@ -5,12 +6,15 @@ use mock::{
 };
 
 use super::{
-    build_module_path, ContextId, MemAdviceProvider, ProcessState, Word, TX_KERNEL_DIR, ZERO,
+    build_module_path, ContextId, Felt, MemAdviceProvider, ProcessState, Word, TX_KERNEL_DIR, ZERO,
 };
 use crate::transaction::{
-    memory::{CREATED_NOTE_ASSET_HASH_OFFSET, CREATED_NOTE_SECTION_OFFSET, NOTE_MEM_SIZE},
-    ToTransactionKernelInputs, FINAL_ACCOUNT_HASH_WORD_IDX, OUTPUT_NOTES_COMMITMENT_WORD_IDX,
-    TX_SCRIPT_ROOT_WORD_IDX,
+    memory::{
+        CREATED_NOTE_ASSETS_OFFSET, CREATED_NOTE_ASSET_HASH_OFFSET, CREATED_NOTE_METADATA_OFFSET,
+        CREATED_NOTE_NUM_ASSETS_OFFSET, CREATED_NOTE_SECTION_OFFSET, NOTE_MEM_SIZE,
+    },
+    notes_try_from_elements, ToTransactionKernelInputs, FINAL_ACCOUNT_HASH_WORD_IDX,
+    OUTPUT_NOTES_COMMITMENT_WORD_IDX, TX_SCRIPT_ROOT_WORD_IDX,
 };
 
 const EPILOGUE_FILE: &str = "epilogue.masm";
@@ -35,7 +39,7 @@ fn test_epilogue() {
         "
     );
 
-    let (stack_inputs, advice_inputs) = executed_transaction.get_kernel_inputs();
+    let (stack_inputs, advice_inputs) = executed_transaction.get_kernel_inputs().unwrap();
     let assembly_file = build_module_path(TX_KERNEL_DIR, EPILOGUE_FILE);
     let process = run_within_tx_kernel(
         imports,
@@ -97,7 +101,7 @@ fn test_compute_created_note_id() {
         "
         );
 
-        let (stack_inputs, advice_inputs) = executed_transaction.get_kernel_inputs();
+        let (stack_inputs, advice_inputs) = executed_transaction.get_kernel_inputs().unwrap();
         let assembly_file = build_module_path(TX_KERNEL_DIR, EPILOGUE_FILE);
         let process = run_within_tx_kernel(
             imports,
@@ -149,7 +153,7 @@ fn test_epilogue_asset_preservation_violation() {
         "
         );
 
-        let (stack_inputs, advice_inputs) = executed_transaction.get_kernel_inputs();
+        let (stack_inputs, advice_inputs) = executed_transaction.get_kernel_inputs().unwrap();
         let assembly_file = build_module_path(TX_KERNEL_DIR, EPILOGUE_FILE);
         let process = run_within_tx_kernel(
             imports,
@@ -185,7 +189,7 @@ fn test_epilogue_increment_nonce_success() {
         "
     );
 
-    let (stack_inputs, advice_inputs) = executed_transaction.get_kernel_inputs();
+    let (stack_inputs, advice_inputs) = executed_transaction.get_kernel_inputs().unwrap();
     let assembly_file = build_module_path(TX_KERNEL_DIR, EPILOGUE_FILE);
     let _process = run_within_tx_kernel(
         imports,
@@ -217,7 +221,7 @@ fn test_epilogue_increment_nonce_violation() {
         "
     );
 
-    let (stack_inputs, advice_inputs) = executed_transaction.get_kernel_inputs();
+    let (stack_inputs, advice_inputs) = executed_transaction.get_kernel_inputs().unwrap();
     let assembly_file = build_module_path(TX_KERNEL_DIR, EPILOGUE_FILE);
     let process = run_within_tx_kernel(
         imports,
@@ -229,3 +233,20 @@ fn test_epilogue_increment_nonce_violation() {
 
     assert!(process.is_err());
 }
+
+#[test]
+fn test_created_note_with_too_many_assets() {
+    let executed_transaction = mock_executed_tx(AssetPreservationStatus::Preserved);
+    let metadata = executed_transaction.output_notes().get_note(0).metadata();
+
+    // lay out the data of a created note which claims to carry one asset more than allowed
+    let num_assets = MAX_ASSETS_PER_NOTE + 1;
+    let mut elements = vec![Word::default(); CREATED_NOTE_ASSETS_OFFSET as usize + num_assets];
+    elements[CREATED_NOTE_METADATA_OFFSET as usize] = metadata.into();
+    elements[CREATED_NOTE_NUM_ASSETS_OFFSET as usize][0] = Felt::from(num_assets as u32);
+
+    assert!(matches!(
+        notes_try_from_elements(&elements),
+        Err(NoteError::TooManyAssets(n)) if n == num_assets
+    ));
+}
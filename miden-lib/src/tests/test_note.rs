@@ -1,4 +1,6 @@
 use miden_objects::{
+    accounts::AccountCode,
+    assembly::ModuleAst,
     notes::Note,
     transaction::{PreparedTransaction, TransactionArgs},
     utils::collections::*,
@@ -16,7 +18,13 @@ use mock::{
 };
 
 use super::{ContextId, Felt, Process, ProcessState, ZERO};
-use crate::transaction::memory::CURRENT_CONSUMED_NOTE_PTR;
+use crate::{
+    notes::well_known::{p2id_note_script, p2idr_note_script, swap_note_script},
+    transaction::{
+        memory::{consumed_note_index, CURRENT_CONSUMED_NOTE_PTR},
+        TransactionKernel,
+    },
+};
 
 #[test]
 fn test_get_sender_no_sender() {
@@ -228,6 +236,65 @@ fn test_get_assets() {
     let _process = run_tx(&transaction).unwrap();
 }
 
+#[test]
+fn test_get_index() {
+    let tx_inputs =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+    // the note procedure returns a different value depending on the index of the note
+    let code = "
+        use.miden::kernels::tx::prologue
+        use.miden::kernels::tx::note->note_internal
+        use.miden::note
+
+        proc.process_note
+            # drop the note inputs
+            dropw dropw dropw dropw
+
+            # get the index of the note
+            exec.note::get_index
+            # => [index]
+
+            if.true
+                push.200
+            else
+                push.100
+            end
+            # => [value]
+
+            # truncate the stack
+            swap drop
+        end
+
+        begin
+            # prepare tx
+            exec.prologue::prepare_transaction
+
+            # process note 0
+            exec.note_internal::prepare_note
+            call.process_note
+            push.100 assert_eq
+
+            # process note 1
+            exec.note_internal::increment_current_consumed_note_ptr drop
+            exec.note_internal::prepare_note
+            call.process_note
+            push.200 assert_eq
+        end
+        ";
+
+    let transaction = prepare_transaction(tx_inputs, None, code, None);
+    let process = run_tx(&transaction).unwrap();
+
+    // the host can determine the index of the last processed note from the kernel memory
+    let current_note_ptr =
+        process.get_mem_value(ContextId::root(), CURRENT_CONSUMED_NOTE_PTR).unwrap()[0].as_int()
+            as u32;
+    assert_eq!(consumed_note_index(current_note_ptr), Some(1));
+    assert_eq!(consumed_note_index(current_note_ptr + 1), None);
+    assert_eq!(consumed_note_index(0), None);
+}
+
 #[test]
 fn test_get_inputs() {
     let tx_inputs =
@@ -382,3 +449,28 @@ fn note_setup_memory_assertions(process: &Process<MockHost>) {
         Felt::from(consumed_note_data_ptr(0))
     );
 }
+
+#[test]
+fn test_well_known_note_scripts() {
+    let assembler = TransactionKernel::assembler();
+    let wallet_src = "
+        use.miden::contracts::wallets::basic->wallet
+        export.wallet::receive_asset
+        export.wallet::send_asset
+    ";
+    let wallet = AccountCode::new(ModuleAst::parse(wallet_src).unwrap(), &assembler).unwrap();
+    let receive_asset = wallet.get_procedure_by_name("receive_asset").unwrap();
+    let send_asset = wallet.get_procedure_by_name("send_asset").unwrap();
+
+    let p2id_targets = p2id_note_script().call_targets(&assembler).unwrap();
+    assert_eq!(p2id_targets, vec![receive_asset]);
+
+    let p2idr_targets = p2idr_note_script().call_targets(&assembler).unwrap();
+    assert_eq!(p2idr_targets, vec![receive_asset]);
+
+    let mut swap_targets = swap_note_script().call_targets(&assembler).unwrap();
+    swap_targets.sort();
+    let mut expected = vec![receive_asset, send_asset];
+    expected.sort();
+    assert_eq!(swap_targets, expected);
+}
@@ -20,17 +20,18 @@ use vm_processor::AdviceInputs;
 use super::{build_module_path, ContextId, Felt, Process, ProcessState, Word, TX_KERNEL_DIR, ZERO};
 use crate::transaction::{
     memory::{
-        MemoryOffset, ACCT_CODE_ROOT_PTR, ACCT_DB_ROOT_PTR, ACCT_ID_AND_NONCE_PTR, ACCT_ID_PTR,
-        ACCT_STORAGE_ROOT_PTR, ACCT_STORAGE_SLOT_TYPE_DATA_OFFSET, ACCT_VAULT_ROOT_PTR,
-        BATCH_ROOT_PTR, BLK_HASH_PTR, BLOCK_METADATA_PTR, BLOCK_NUMBER_IDX,
-        CHAIN_MMR_NUM_LEAVES_PTR, CHAIN_MMR_PEAKS_PTR, CHAIN_ROOT_PTR, CONSUMED_NOTE_ARGS_OFFSET,
-        CONSUMED_NOTE_ASSETS_HASH_OFFSET, CONSUMED_NOTE_ASSETS_OFFSET, CONSUMED_NOTE_ID_OFFSET,
-        CONSUMED_NOTE_INPUTS_HASH_OFFSET, CONSUMED_NOTE_METADATA_OFFSET,
-        CONSUMED_NOTE_NUM_ASSETS_OFFSET, CONSUMED_NOTE_NUM_INPUTS_OFFSET,
-        CONSUMED_NOTE_SCRIPT_ROOT_OFFSET, CONSUMED_NOTE_SECTION_OFFSET,
-        CONSUMED_NOTE_SERIAL_NUM_OFFSET, INIT_ACCT_HASH_PTR, INIT_NONCE_PTR, NOTE_ROOT_PTR,
-        NULLIFIER_COM_PTR, NULLIFIER_DB_ROOT_PTR, PREV_BLOCK_HASH_PTR, PROOF_HASH_PTR,
-        PROTOCOL_VERSION_IDX, TIMESTAMP_IDX, TX_SCRIPT_ROOT_PTR,
+        read_chain_mmr_peaks, write_chain_mmr_peaks, MemoryOffset, ACCT_CODE_ROOT_PTR,
+        ACCT_DB_ROOT_PTR, ACCT_ID_AND_NONCE_PTR, ACCT_ID_PTR, ACCT_STORAGE_ROOT_PTR,
+        ACCT_STORAGE_SLOT_TYPE_DATA_OFFSET, ACCT_VAULT_ROOT_PTR, BATCH_ROOT_PTR, BLK_HASH_PTR,
+        BLOCK_METADATA_PTR, BLOCK_NUMBER_IDX, CHAIN_MMR_NUM_LEAVES_PTR, CHAIN_MMR_PEAKS_PTR,
+        CHAIN_ROOT_PTR, CONSUMED_NOTE_ARGS_OFFSET, CONSUMED_NOTE_ASSETS_HASH_OFFSET,
+        CONSUMED_NOTE_ASSETS_OFFSET, CONSUMED_NOTE_ID_OFFSET, CONSUMED_NOTE_INPUTS_HASH_OFFSET,
+        CONSUMED_NOTE_METADATA_OFFSET, CONSUMED_NOTE_NUM_ASSETS_OFFSET,
+        CONSUMED_NOTE_NUM_INPUTS_OFFSET, CONSUMED_NOTE_SCRIPT_ROOT_OFFSET,
+        CONSUMED_NOTE_SECTION_OFFSET, CONSUMED_NOTE_SERIAL_NUM_OFFSET, INIT_ACCT_HASH_PTR,
+        INIT_NONCE_PTR, NOTE_ROOT_PTR, NULLIFIER_COM_PTR, NULLIFIER_DB_ROOT_PTR,
+        PREV_BLOCK_HASH_PTR, PROOF_HASH_PTR, PROTOCOL_VERSION_IDX, TIMESTAMP_IDX,
+        TX_SCRIPT_ROOT_PTR,
     },
     TransactionKernel,
 };
@@ -207,6 +208,29 @@ fn chain_mmr_memory_assertions(process: &Process<MockHost>, prepared_tx: &Prepar
         );
         assert_eq!(read_root_mem_value(process, CHAIN_MMR_PEAKS_PTR + i), Word::from(peak));
     }
+
+    // The peaks read back from memory should match the chain MMR peaks
+    let peaks = read_chain_mmr_peaks(|addr| read_root_mem_value(process, addr)).unwrap();
+    assert_eq!(peaks, chain_mmr.peaks());
+}
+
+#[test]
+fn test_chain_mmr_peaks_layout() {
+    let tx_inputs =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+    let peaks = tx_inputs.block_chain().peaks();
+
+    let memory = write_chain_mmr_peaks(&peaks).into_iter().collect::<BTreeMap<_, _>>();
+
+    // the number of leaves is stored in the first element of the first word
+    assert_eq!(memory[&CHAIN_MMR_NUM_LEAVES_PTR][0], Felt::new(peaks.num_leaves() as u64));
+
+    // peaks are padded to at least 16 words
+    assert_eq!(memory.len(), 1 + 16);
+
+    let read_peaks =
+        read_chain_mmr_peaks(|addr| memory.get(&addr).copied().unwrap_or_default()).unwrap();
+    assert_eq!(read_peaks, peaks);
 }
 
 fn account_data_memory_assertions(process: &Process<MockHost>, inputs: &PreparedTransaction) {
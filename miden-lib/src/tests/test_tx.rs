@@ -197,12 +197,12 @@ fn test_get_output_notes_hash() {
     end
     ",
         recipient_1 = prepare_word(&output_note_1.recipient()),
-        tag_1 = output_note_1.metadata().tag(),
+        tag_1 = output_note_1.metadata().tag().inner(),
         asset_1 = prepare_word(&Word::from(
             **output_note_1.assets().iter().take(1).collect::<Vec<_>>().first().unwrap()
         )),
         recipient_2 = prepare_word(&output_note_2.recipient()),
-        tag_2 = output_note_2.metadata().tag(),
+        tag_2 = output_note_2.metadata().tag().inner(),
         asset_2 = prepare_word(&Word::from(
             **output_note_2.assets().iter().take(1).collect::<Vec<_>>().first().unwrap()
         )),
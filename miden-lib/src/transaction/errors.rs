@@ -1,12 +1,13 @@
 use core::fmt;
 
-use miden_objects::{accounts::AccountStorage, utils::string::*, AssetError, Digest};
+use miden_objects::{accounts::AccountStorage, utils::string::*, AccountError, AssetError, Digest};
 
 // TRANSACTION KERNEL ERROR
 // ================================================================================================
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum TransactionKernelError {
+    InvalidNonceIncrement(AccountError),
     InvalidStorageSlotIndex(u64),
     MalformedAssetOnAccountVaultUpdate(AssetError),
     MissingStorageSlotValue(u8, String),
@@ -16,6 +17,9 @@ pub enum TransactionKernelError {
 impl fmt::Display for TransactionKernelError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            Self::InvalidNonceIncrement(err) => {
+                write!(f, "account nonce increment is invalid: {err}")
+            },
             Self::InvalidStorageSlotIndex(index) => {
                 let num_slots = AccountStorage::NUM_STORAGE_SLOTS;
                 write!(f, "storage slot index {index} is invalid, must be smaller than {num_slots}")
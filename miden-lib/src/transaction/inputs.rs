@@ -5,7 +5,7 @@ use miden_objects::{
         TransactionInputs, TransactionScript, TransactionWitness,
     },
     vm::{AdviceInputs, StackInputs},
-    Felt, Word, ZERO,
+    Felt, TransactionInputError, Word, ZERO,
 };
 
 use super::TransactionKernel;
@@ -17,11 +17,14 @@ use crate::utils::{collections::*, vec};
 /// Defines how inputs required to execute a transaction kernel can be extracted from self.
 pub trait ToTransactionKernelInputs {
     /// Returns stack and advice inputs required to execute the transaction kernel.
-    fn get_kernel_inputs(&self) -> (StackInputs, AdviceInputs);
+    ///
+    /// # Errors
+    /// Returns an error if any of the input notes does not have an inclusion proof.
+    fn get_kernel_inputs(&self) -> Result<(StackInputs, AdviceInputs), TransactionInputError>;
 }
 
 impl ToTransactionKernelInputs for PreparedTransaction {
-    fn get_kernel_inputs(&self) -> (StackInputs, AdviceInputs) {
+    fn get_kernel_inputs(&self) -> Result<(StackInputs, AdviceInputs), TransactionInputError> {
         let account = self.account();
         let stack_inputs = TransactionKernel::build_input_stack(
             account.id(),
@@ -31,14 +34,14 @@ impl ToTransactionKernelInputs for PreparedTransaction {
         );
 
         let mut advice_inputs = AdviceInputs::default();
-        extend_advice_inputs(self.tx_inputs(), self.tx_args(), &mut advice_inputs);
+        extend_advice_inputs(self.tx_inputs(), self.tx_args(), &mut advice_inputs)?;
 
-        (stack_inputs, advice_inputs)
+        Ok((stack_inputs, advice_inputs))
     }
 }
 
 impl ToTransactionKernelInputs for ExecutedTransaction {
-    fn get_kernel_inputs(&self) -> (StackInputs, AdviceInputs) {
+    fn get_kernel_inputs(&self) -> Result<(StackInputs, AdviceInputs), TransactionInputError> {
         let account = self.initial_account();
         let stack_inputs = TransactionKernel::build_input_stack(
             account.id(),
@@ -48,14 +51,14 @@ impl ToTransactionKernelInputs for ExecutedTransaction {
         );
 
         let mut advice_inputs = self.advice_witness().clone();
-        extend_advice_inputs(self.tx_inputs(), self.tx_args(), &mut advice_inputs);
+        extend_advice_inputs(self.tx_inputs(), self.tx_args(), &mut advice_inputs)?;
 
-        (stack_inputs, advice_inputs)
+        Ok((stack_inputs, advice_inputs))
     }
 }
 
 impl ToTransactionKernelInputs for TransactionWitness {
-    fn get_kernel_inputs(&self) -> (StackInputs, AdviceInputs) {
+    fn get_kernel_inputs(&self) -> Result<(StackInputs, AdviceInputs), TransactionInputError> {
         let account = self.account();
         let stack_inputs = TransactionKernel::build_input_stack(
             account.id(),
@@ -65,9 +68,9 @@ impl ToTransactionKernelInputs for TransactionWitness {
         );
 
         let mut advice_inputs = self.advice_witness().clone();
-        extend_advice_inputs(self.tx_inputs(), self.tx_args(), &mut advice_inputs);
+        extend_advice_inputs(self.tx_inputs(), self.tx_args(), &mut advice_inputs)?;
 
-        (stack_inputs, advice_inputs)
+        Ok((stack_inputs, advice_inputs))
     }
 }
 
@@ -80,19 +83,24 @@ impl ToTransactionKernelInputs for TransactionWitness {
 /// This includes the initial account, an optional account seed (required for new accounts), and
 /// the input note data, including core note data + authentication paths all the way to the root
 /// of one of chain MMR peaks.
+///
+/// # Errors
+/// Returns an error if any of the input notes does not have an inclusion proof.
 fn extend_advice_inputs(
     tx_inputs: &TransactionInputs,
     tx_args: &TransactionArgs,
     advice_inputs: &mut AdviceInputs,
-) {
+) -> Result<(), TransactionInputError> {
     // build the advice stack
     build_advice_stack(tx_inputs, tx_args.tx_script(), advice_inputs);
 
     // build the advice map and Merkle store for relevant components
     add_chain_mmr_to_advice_inputs(tx_inputs.block_chain(), advice_inputs);
     add_account_to_advice_inputs(tx_inputs.account(), tx_inputs.account_seed(), advice_inputs);
-    add_input_notes_to_advice_inputs(tx_inputs.input_notes(), tx_args, advice_inputs);
+    add_input_notes_to_advice_inputs(tx_inputs.input_notes(), tx_args, advice_inputs)?;
     add_tx_script_inputs_to_advice_map(tx_args.tx_script(), advice_inputs);
+
+    Ok(())
 }
 
 // ADVICE STACK BUILDER
@@ -273,20 +281,25 @@ fn add_account_to_advice_inputs(
 /// - inputs_hash |-> inputs
 /// - asset_hash |-> assets
 /// - notes_hash |-> combined note data
+///
+/// # Errors
+/// Returns an error if any of the notes does not have an inclusion proof.
 fn add_input_notes_to_advice_inputs(
     notes: &InputNotes,
     tx_args: &TransactionArgs,
     inputs: &mut AdviceInputs,
-) {
+) -> Result<(), TransactionInputError> {
     // if there are no input notes, nothing is added to the advice inputs
     if notes.is_empty() {
-        return;
+        return Ok(());
     }
 
     let mut note_data = Vec::new();
     for input_note in notes.iter() {
         let note = input_note.note();
-        let proof = input_note.proof();
+        let proof = input_note
+            .proof()
+            .ok_or(TransactionInputError::InputNoteMissingProof(note.id()))?;
         let note_arg = tx_args.get_note_args(note.id()).unwrap_or(&[ZERO; 4]);
 
         // insert note inputs and assets into the advice map
@@ -330,6 +343,8 @@ fn add_input_notes_to_advice_inputs(
 
     // insert the combined note data into the advice map
     inputs.extend_map([(notes.commitment(), note_data)]);
+
+    Ok(())
 }
 
 // TRANSACTION SCRIPT INJECTOR
@@ -1,3 +1,9 @@
+use miden_objects::{
+    crypto::merkle::{MmrError, MmrPeaks},
+    utils::{collections::*, group_slice_elements, vec},
+    Digest, Felt, Word, ZERO,
+};
+
 // TYPE ALIASES
 // ================================================================================================
 
@@ -114,6 +120,50 @@ pub const CHAIN_MMR_NUM_LEAVES_PTR: MemoryAddress = 300;
 /// The memory address at which the chain mmr peaks are stored
 pub const CHAIN_MMR_PEAKS_PTR: MemoryAddress = 301;
 
+/// Returns the chain MMR data laid out as the transaction kernel stores it in memory.
+///
+/// The number of leaves is stored as [num_leaves, 0, 0, 0] at [CHAIN_MMR_NUM_LEAVES_PTR], and the
+/// peaks are stored one word per address starting at [CHAIN_MMR_PEAKS_PTR]. The peaks are padded
+/// with empty words to at least 16 peaks (and to an even number of peaks beyond that), which
+/// matches the layout produced by `mmr::unpack`.
+pub fn write_chain_mmr_peaks(peaks: &MmrPeaks) -> Vec<(MemoryAddress, Word)> {
+    let mut memory = vec![(
+        CHAIN_MMR_NUM_LEAVES_PTR,
+        [Felt::new(peaks.num_leaves() as u64), ZERO, ZERO, ZERO],
+    )];
+
+    let padded_peaks = peaks.flatten_and_pad_peaks();
+    memory.extend(
+        group_slice_elements::<Felt, 4>(&padded_peaks)
+            .iter()
+            .enumerate()
+            .map(|(i, peak)| (CHAIN_MMR_PEAKS_PTR + i as MemoryAddress, *peak)),
+    );
+
+    memory
+}
+
+/// Reads the chain MMR peaks from memory laid out as described in [write_chain_mmr_peaks].
+///
+/// The provided closure is used to read a word from the specified memory address.
+///
+/// # Errors
+/// Returns an error if the number of peaks implied by the number of leaves is inconsistent with
+/// the data read from memory.
+pub fn read_chain_mmr_peaks<F>(read_word: F) -> Result<MmrPeaks, MmrError>
+where
+    F: Fn(MemoryAddress) -> Word,
+{
+    let num_leaves = read_word(CHAIN_MMR_NUM_LEAVES_PTR)[0].as_int() as usize;
+    let num_peaks = num_leaves.count_ones();
+
+    let peaks = (0..num_peaks)
+        .map(|i| Digest::from(read_word(CHAIN_MMR_PEAKS_PTR + i)))
+        .collect::<Vec<_>>();
+
+    MmrPeaks::new(num_leaves, peaks)
+}
+
 // ACCOUNT DATA
 // ------------------------------------------------------------------------------------------------
 
@@ -211,6 +261,19 @@ pub const CONSUMED_NOTE_DATA_SECTION_OFFSET: MemoryAddress = 1_064_960;
 /// The memory address at which the number of consumed notes is stored.
 pub const CONSUMED_NOTE_NUM_PTR: MemoryAddress = CONSUMED_NOTE_SECTION_OFFSET;
 
+/// Returns the index of the consumed note whose data segment starts at the specified memory
+/// address, or None if the address is not the start of a consumed note data segment.
+///
+/// This can be used to determine the index of the note currently being processed from the value
+/// stored at [CURRENT_CONSUMED_NOTE_PTR].
+pub fn consumed_note_index(ptr: MemoryAddress) -> Option<u32> {
+    let offset = ptr.checked_sub(CONSUMED_NOTE_DATA_SECTION_OFFSET)?;
+    if offset % NOTE_MEM_SIZE != 0 {
+        return None;
+    }
+    Some(offset / NOTE_MEM_SIZE)
+}
+
 /// The offsets at which data of a consumed note is stored relative to the start of its data segment.
 pub const CONSUMED_NOTE_ID_OFFSET: MemoryOffset = 0;
 pub const CONSUMED_NOTE_SERIAL_NUM_OFFSET: MemoryOffset = 1;
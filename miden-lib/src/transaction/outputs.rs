@@ -4,7 +4,7 @@ use miden_objects::{
     notes::{NoteAssets, NoteId, NoteMetadata},
     transaction::OutputNote,
     utils::collections::*,
-    AccountError, Digest, NoteError, Word, WORD_SIZE,
+    AccountError, Digest, NoteError, Word, MAX_ASSETS_PER_NOTE, WORD_SIZE,
 };
 
 use super::memory::{
@@ -48,6 +48,14 @@ pub fn parse_final_account_stub(elements: &[Word]) -> Result<AccountStub, Accoun
 // NOTES EXTRACTOR
 // ================================================================================================
 
+/// Parses the data of a note created by a transaction, as laid out in the created notes section of
+/// the kernel memory, into an [OutputNote].
+///
+/// # Errors
+/// Returns an error if:
+/// - The data is too short to hold the note core data or the note assets.
+/// - The number of assets in the note exceeds [MAX_ASSETS_PER_NOTE].
+/// - The asset data is invalid or inconsistent with the asset hash or the note ID.
 pub fn notes_try_from_elements(elements: &[Word]) -> Result<OutputNote, NoteError> {
     if elements.len() < CREATED_NOTE_CORE_DATA_SIZE {
         return Err(NoteError::InvalidStubDataLen(elements.len()));
@@ -59,6 +67,12 @@ pub fn notes_try_from_elements(elements: &[Word]) -> Result<OutputNote, NoteErro
     let num_assets = elements[CREATED_NOTE_NUM_ASSETS_OFFSET as usize][0];
     let asset_hash: Digest = elements[CREATED_NOTE_ASSET_HASH_OFFSET as usize].into();
 
+    // reject notes with too many assets before reading the asset data, as such notes cannot fit
+    // into the data segment of a single created note
+    if num_assets.as_int() > MAX_ASSETS_PER_NOTE as u64 {
+        return Err(NoteError::too_many_assets(num_assets.as_int() as usize));
+    }
+
     if elements.len()
         < (CREATED_NOTE_ASSETS_OFFSET as usize + num_assets.as_int() as usize) * WORD_SIZE
     {
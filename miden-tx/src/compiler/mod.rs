@@ -1,6 +1,14 @@
+use core::ops::ControlFlow;
+
+use miden_lib::{notes::well_known::p2id_note_script, rotate_auth_key_script};
 use miden_objects::{
-    assembly::{Assembler, AssemblyContext, ModuleAst, ProgramAst},
-    transaction::{InputNotes, TransactionScript},
+    assembly::{Assembler, AssemblyContext, Library, ModuleAst, ProgramAst},
+    assets::Asset,
+    crypto::dsa::rpo_falcon512::PublicKey,
+    notes::{build_recipient, NoteInputs, NoteTag, NoteType},
+    transaction::{InputNote, InputNotes, TransactionScript, TxScriptArgs},
+    utils::format,
+    vm::collect_call_branches,
     Felt, NoteError, TransactionScriptError, Word,
 };
 
@@ -8,7 +16,7 @@ use super::{
     AccountCode, AccountId, CodeBlock, Digest, NoteScript, Program, TransactionCompilerError,
     TransactionKernel,
 };
-use crate::utils::{collections::*, vec};
+use crate::utils::collections::*;
 
 #[cfg(test)]
 mod tests;
@@ -27,7 +35,9 @@ mod tests;
 pub struct TransactionCompiler {
     assembler: Assembler,
     account_procedures: BTreeMap<AccountId, Vec<Digest>>,
+    account_codes: BTreeMap<AccountId, AccountCode>,
     kernel_main: CodeBlock,
+    canonical_note_order: bool,
 }
 
 impl TransactionCompiler {
@@ -46,8 +56,94 @@ impl TransactionCompiler {
         TransactionCompiler {
             assembler,
             account_procedures: BTreeMap::default(),
+            account_codes: BTreeMap::default(),
             kernel_main,
+            canonical_note_order: false,
+        }
+    }
+
+    /// Returns a [TransactionCompiler] which only compiles transactions whose input notes are
+    /// arranged in canonical order (see [canonical_note_order](miden_objects::transaction::canonical_note_order)).
+    ///
+    /// This guarantees that the input notes commitment of the compiled transactions does not
+    /// depend on the order in which the notes were provided by the client.
+    pub fn with_canonical_note_order(mut self) -> Self {
+        self.canonical_note_order = true;
+        self
+    }
+
+    /// Returns a [TransactionCompiler] whose assembler has the provided libraries registered in
+    /// addition to the transaction kernel and the standard libraries.
+    ///
+    /// This allows account code, note scripts and transaction scripts to import procedures from
+    /// the specified libraries.
+    ///
+    /// # Errors
+    /// Returns an error if any of the libraries could not be registered with the assembler (e.g.,
+    /// if its namespace is already taken by another library).
+    pub fn with_libraries<L: Library>(
+        mut self,
+        libraries: &[L],
+    ) -> Result<Self, TransactionCompilerError> {
+        for library in libraries {
+            self.assembler = self
+                .assembler
+                .with_library(library)
+                .map_err(TransactionCompilerError::LoadLibraryFailed)?;
+        }
+        Ok(self)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the interface (vector of procedure digests) registered for the specified account,
+    /// or None if no interface has been loaded for this account.
+    pub fn interface_for(&self, account_id: AccountId) -> Option<&[Digest]> {
+        self.account_procedures.get(&account_id).map(|procedures| procedures.as_slice())
+    }
+
+    /// Returns the MAST root of the procedure with the specified name exported by the code of
+    /// the specified account.
+    ///
+    /// Returns None if the account has no procedure with this name, or if the account was not
+    /// loaded via [TransactionCompiler::load_account()] (only procedure digests are known for
+    /// accounts loaded via [TransactionCompiler::load_account_interface()]).
+    pub fn resolve_procedure(&self, account_id: AccountId, proc_name: &str) -> Option<Digest> {
+        self.account_codes
+            .get(&account_id)
+            .and_then(|account_code| account_code.get_procedure_by_name(proc_name))
+    }
+
+    /// Checks that the interface registered for the specified account consists of exactly the
+    /// procedures of the provided account code.
+    ///
+    /// This detects interfaces which were registered for a different (e.g., stale) version of the
+    /// account code. The order of procedures in the interface is not taken into account.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - No interface has been loaded for the specified account.
+    /// - The loaded interface does not match the procedures of the provided account code.
+    pub fn verify_account_code(
+        &self,
+        account_id: AccountId,
+        code: &AccountCode,
+    ) -> Result<(), TransactionCompilerError> {
+        let interface = self
+            .interface_for(account_id)
+            .ok_or(TransactionCompilerError::AccountInterfaceNotFound(account_id))?;
+
+        let interface_procedures = interface.iter().collect::<BTreeSet<_>>();
+        let code_procedures = code.procedures().iter().collect::<BTreeSet<_>>();
+        if interface.len() != code.procedures().len() || interface_procedures != code_procedures {
+            return Err(TransactionCompilerError::AccountInterfaceMismatch(
+                account_id,
+                code.root(),
+            ));
         }
+
+        Ok(())
     }
 
     // ACCOUNT CODE AND NOTE SCRIPT COMPILERS
@@ -63,17 +159,80 @@ impl TransactionCompiler {
         let account_code = AccountCode::new(account_code, &self.assembler)
             .map_err(TransactionCompilerError::LoadAccountFailed)?;
         self.account_procedures.insert(account_id, account_code.procedures().to_vec());
+        self.account_codes.insert(account_id, account_code.clone());
         Ok(account_code)
     }
 
+    /// Replaces the code of an already loaded account with the provided module, e.g., after the
+    /// account updated its code.
+    ///
+    /// The module is compiled and its procedures replace the interface registered for the account.
+    /// Returns the procedures which were a part of the previous interface but are not exported by
+    /// the new code; notes calling these procedures are no longer compatible with the account.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - No interface has been loaded for the specified account.
+    /// - The provided module cannot be compiled into account code. In this case the previous
+    ///   interface of the account is retained.
+    pub fn migrate_account_code(
+        &mut self,
+        account_id: AccountId,
+        new_code: ModuleAst,
+    ) -> Result<Vec<Digest>, TransactionCompilerError> {
+        let old_procedures = self
+            .account_procedures
+            .get(&account_id)
+            .cloned()
+            .ok_or(TransactionCompilerError::AccountInterfaceNotFound(account_id))?;
+
+        let account_code = self.load_account(account_id, new_code)?;
+
+        Ok(old_procedures
+            .into_iter()
+            .filter(|procedure| !account_code.procedures().contains(procedure))
+            .collect())
+    }
+
+    /// Associates the procedures of the provided, already compiled, [AccountCode] with the
+    /// specified account ID.
+    ///
+    /// Unlike [TransactionCompiler::load_account()], the account code is not recompiled. Thus, the
+    /// procedures of the account are not known to the assembler of this compiler, and scripts
+    /// which call these procedures must be compiled by an assembler which compiled the account
+    /// code.
+    pub fn load_account_code(&mut self, account_id: AccountId, account_code: AccountCode) {
+        self.account_procedures.insert(account_id, account_code.procedures().to_vec());
+        self.account_codes.insert(account_id, account_code);
+    }
+
     /// Loads the provided account interface (vector of procedure digests) into this compiler.
     /// Returns the old account interface if it previously existed.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The provided interface does not contain any procedures.
+    /// - The provided interface contains duplicate procedures.
     pub fn load_account_interface(
         &mut self,
         account_id: AccountId,
         procedures: Vec<Digest>,
-    ) -> Option<Vec<Digest>> {
-        self.account_procedures.insert(account_id, procedures)
+    ) -> Result<Option<Vec<Digest>>, TransactionCompilerError> {
+        if procedures.is_empty() {
+            return Err(TransactionCompilerError::AccountInterfaceEmpty(account_id));
+        }
+
+        let mut unique_procedures = BTreeSet::new();
+        for procedure in procedures.iter() {
+            if !unique_procedures.insert(procedure) {
+                return Err(TransactionCompilerError::AccountInterfaceDuplicateProcedure(
+                    account_id, *procedure,
+                ));
+            }
+        }
+
+        self.account_codes.remove(&account_id);
+        Ok(self.account_procedures.insert(account_id, procedures))
     }
 
     /// Compiles the provided program into the [NoteScript] and checks (to the extent possible)
@@ -94,6 +253,7 @@ impl TransactionCompiler {
             verify_program_account_compatibility(
                 &code_block,
                 &self.get_target_interface(note_target)?,
+                &self.account_procedures,
                 ScriptType::NoteScript,
             )?;
         }
@@ -101,6 +261,66 @@ impl TransactionCompiler {
         Ok(note_script)
     }
 
+    /// Analyzes the compatibility of the provided note script with the target account interface.
+    ///
+    /// The returned [CompatibilityReport] lists the call targets of each execution branch of the
+    /// script which are not a part of the target account interface.
+    ///
+    /// # Errors
+    /// Returns an error if the note script cannot be compiled or if the target account interface
+    /// is not known to this compiler.
+    pub fn analyze_note(
+        &self,
+        note_script: &NoteScript,
+        target_account_proc: ScriptTarget,
+    ) -> Result<CompatibilityReport, TransactionCompilerError> {
+        let code = note_script.code();
+        let code_block = self
+            .assembler
+            .compile_in_context(code, &mut AssemblyContext::for_program(Some(code)))
+            .map_err(TransactionCompilerError::CompileNoteScriptFailed)?;
+        let target_account_interface = self.get_target_interface(target_account_proc)?;
+
+        Ok(CompatibilityReport::new(&code_block, &target_account_interface))
+    }
+
+    /// Returns true if the provided note script can be executed against the specified account.
+    ///
+    /// The compatibility is determined in the same way as by
+    /// [TransactionCompiler::compile_note_script()], but an incompatible note script results in
+    /// `Ok(false)` rather than in an error.
+    ///
+    /// # Errors
+    /// Returns an error if the note script cannot be compiled or if the interface of the specified
+    /// account is not known to this compiler.
+    pub fn is_note_compatible(
+        &self,
+        note_script: &NoteScript,
+        account_id: AccountId,
+    ) -> Result<bool, TransactionCompilerError> {
+        let target_account_interface =
+            self.get_target_interface(ScriptTarget::AccountId(account_id))?;
+        let code = note_script.code();
+        let code_block = self
+            .assembler
+            .compile_in_context(code, &mut AssemblyContext::for_program(Some(code)))
+            .map_err(TransactionCompilerError::CompileNoteScriptFailed)?;
+
+        match verify_program_account_compatibility(
+            &code_block,
+            &target_account_interface,
+            &self.account_procedures,
+            ScriptType::NoteScript,
+        ) {
+            Ok(()) => Ok(true),
+            Err(
+                TransactionCompilerError::NoteIncompatibleWithAccountInterface(_)
+                | TransactionCompilerError::ForeignAccountCall(_),
+            ) => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
     /// Constructs a [TransactionScript] by compiling the provided source code and checking the
     /// compatibility of the resulting program with the target account interfaces.
     pub fn compile_tx_script<T>(
@@ -112,22 +332,32 @@ impl TransactionCompiler {
     where
         T: IntoIterator<Item = (Word, Vec<Felt>)>,
     {
-        let (tx_script, code_block) =
-            TransactionScript::new(tx_script_ast, tx_script_inputs, &self.assembler).map_err(
-                |e| match e {
-                    TransactionScriptError::ScriptCompilationError(asm_error) => {
-                        TransactionCompilerError::CompileTxScriptFailed(asm_error)
-                    },
-                },
-            )?;
-        for target in target_account_proc.into_iter() {
-            verify_program_account_compatibility(
-                &code_block,
-                &self.get_target_interface(target)?,
-                ScriptType::TransactionScript,
-            )?;
-        }
-        Ok(tx_script)
+        let compiled = TransactionScript::new(tx_script_ast, tx_script_inputs, &self.assembler);
+        self.verify_tx_script(compiled, target_account_proc)
+    }
+
+    /// Constructs a [TransactionScript] by compiling the provided source code with the code
+    /// loading the provided arguments onto the stack prepended to it, and checks the
+    /// compatibility of the resulting program with the target account interfaces.
+    ///
+    /// The advice map entry carrying the arguments is added to the provided script inputs.
+    pub fn compile_tx_script_with_args<T>(
+        &self,
+        tx_script_ast: ProgramAst,
+        tx_script_args: TxScriptArgs,
+        tx_script_inputs: T,
+        target_account_proc: Vec<ScriptTarget>,
+    ) -> Result<TransactionScript, TransactionCompilerError>
+    where
+        T: IntoIterator<Item = (Word, Vec<Felt>)>,
+    {
+        let compiled = TransactionScript::with_args(
+            tx_script_ast,
+            tx_script_args,
+            tx_script_inputs,
+            &self.assembler,
+        );
+        self.verify_tx_script(compiled, target_account_proc)
     }
 
     // TRANSACTION PROGRAM BUILDER
@@ -136,18 +366,37 @@ impl TransactionCompiler {
     /// the specified account. Returns the the compiled transaction program.
     ///
     /// The account is assumed to have been previously loaded into this compiler.
+    ///
+    /// If the compiler was created with [TransactionCompiler::with_canonical_note_order()], an
+    /// error is returned when the input notes are not arranged in canonical order.
     pub fn compile_transaction(
         &self,
         account_id: AccountId,
         notes: &InputNotes,
         tx_script: Option<&ProgramAst>,
+    ) -> Result<Program, TransactionCompilerError> {
+        self.compile_transaction_with_progress(account_id, notes, tx_script, &mut |_, _| {
+            ControlFlow::Continue(())
+        })
+    }
+
+    /// Compiles a transaction in the same way as [TransactionCompiler::compile_transaction()],
+    /// and reports the progress of note compilation to the provided callback.
+    ///
+    /// The callback is invoked after each note script is compiled with the number of notes
+    /// compiled so far and the total number of notes. Returning [ControlFlow::Break] from the
+    /// callback stops the compilation and an error is returned.
+    pub fn compile_transaction_with_progress(
+        &self,
+        account_id: AccountId,
+        notes: &InputNotes,
+        tx_script: Option<&ProgramAst>,
+        progress: &mut dyn FnMut(usize, usize) -> ControlFlow<()>,
     ) -> Result<Program, TransactionCompilerError> {
         // Fetch the account interface from the `account_procedures` map. Return an error if the
         // interface is not found.
         let target_account_interface = self
-            .account_procedures
-            .get(&account_id)
-            .cloned()
+            .interface_for(account_id)
             .ok_or(TransactionCompilerError::AccountInterfaceNotFound(account_id))?;
 
         // Transaction must contain at least one input note or a transaction script
@@ -155,12 +404,16 @@ impl TransactionCompiler {
             return Err(TransactionCompilerError::NoTransactionDriver);
         }
 
+        if self.canonical_note_order && !notes.is_canonical() {
+            return Err(TransactionCompilerError::InputNotesNotInCanonicalOrder);
+        }
+
         // Create the [AssemblyContext] for compilation of notes scripts and the transaction script
         let mut assembly_context = AssemblyContext::for_program(None);
 
         // Compile note scripts
         let note_script_programs =
-            self.compile_notes(&target_account_interface, notes, &mut assembly_context)?;
+            self.compile_notes(target_account_interface, notes, &mut assembly_context, progress)?;
 
         // Compile the transaction script
         let tx_script_program = match tx_script {
@@ -172,31 +425,57 @@ impl TransactionCompiler {
             None => None,
         };
 
-        // Create [CodeBlockTable] from [AssemblyContext]
-        let mut cb_table = self
-            .assembler
-            .build_cb_table(assembly_context)
-            .map_err(TransactionCompilerError::BuildCodeBlockTableFailed)?;
+        self.build_transaction_program(assembly_context, note_script_programs, tx_script_program)
+    }
 
-        // insert note roots into [CodeBlockTable]
-        note_script_programs.into_iter().for_each(|note_root| {
-            cb_table.insert(note_root);
-        });
+    /// Compiles a transaction which carries out the provided [Intent] against the specified
+    /// account.
+    ///
+    /// The intent is translated into the input notes and the transaction script returned by
+    /// [Intent::input_notes()] and [Intent::tx_script()]. Returns the compiled transaction program
+    /// together with the root of the transaction script. To execute the transaction, the same
+    /// script (along with the inputs required for authentication) must be provided as a part of
+    /// the transaction arguments.
+    ///
+    /// The account is assumed to have been previously loaded into this compiler.
+    ///
+    /// # Errors
+    /// Returns an error if the intent cannot be translated into a transaction, or if the
+    /// resulting transaction cannot be compiled against the account.
+    pub fn compile_intent(
+        &self,
+        account_id: AccountId,
+        intent: Intent,
+    ) -> Result<(Program, Digest), TransactionCompilerError> {
+        let target_account_interface = self
+            .interface_for(account_id)
+            .ok_or(TransactionCompilerError::AccountInterfaceNotFound(account_id))?;
 
-        // insert transaction script into [CodeBlockTable]
-        if let Some(tx_script_program) = tx_script_program {
-            cb_table.insert(tx_script_program);
-        }
+        // an intent consumes at most one note, and thus, its notes are always in canonical order
+        let notes = intent.input_notes();
+        let tx_script = intent.tx_script()?;
 
-        // Create transaction program with kernel
-        let program = Program::with_kernel(
-            self.kernel_main.clone(),
-            self.assembler.kernel().clone(),
-            cb_table,
-        );
+        let mut assembly_context = AssemblyContext::for_program(None);
+        let note_script_programs = self.compile_notes(
+            target_account_interface,
+            &notes,
+            &mut assembly_context,
+            &mut |_, _| ControlFlow::Continue(()),
+        )?;
+        let tx_script_program = self.compile_tx_script_program(
+            &tx_script,
+            &mut assembly_context,
+            target_account_interface,
+        )?;
+        let tx_script_root = tx_script_program.hash();
 
-        // Create compiled transaction
-        Ok(program)
+        let program = self.build_transaction_program(
+            assembly_context,
+            note_script_programs,
+            Some(tx_script_program),
+        )?;
+
+        Ok((program, tx_script_root))
     }
 
     // HELPER METHODS
@@ -205,16 +484,19 @@ impl TransactionCompiler {
     /// Compiles the provided notes into [CodeBlock]s (programs) and verifies that each note is
     /// compatible with the target account interfaces. Returns a vector of the compiled note
     /// programs.
+    ///
+    /// The progress callback is invoked after each note is compiled.
     fn compile_notes(
         &self,
         target_account_interface: &[Digest],
         notes: &InputNotes,
         assembly_context: &mut AssemblyContext,
+        progress: &mut dyn FnMut(usize, usize) -> ControlFlow<()>,
     ) -> Result<Vec<CodeBlock>, TransactionCompilerError> {
         let mut note_programs = Vec::new();
 
         // Create and verify note programs. Note programs are verified against the target account.
-        for recorded_note in notes.iter() {
+        for (index, recorded_note) in notes.iter().enumerate() {
             let note_program = self
                 .assembler
                 .compile_in_context(recorded_note.note().script().code(), assembly_context)
@@ -222,14 +504,104 @@ impl TransactionCompiler {
             verify_program_account_compatibility(
                 &note_program,
                 target_account_interface,
+                &self.account_procedures,
                 ScriptType::NoteScript,
             )?;
             note_programs.push(note_program);
+
+            if progress(index + 1, notes.num_notes()).is_break() {
+                return Err(TransactionCompilerError::CompilationCancelled);
+            }
         }
 
         Ok(note_programs)
     }
 
+    /// Builds a transaction program from the provided compiled note scripts and an optional
+    /// compiled transaction script.
+    ///
+    /// The [AssemblyContext] must be the one in which the scripts were compiled.
+    fn build_transaction_program(
+        &self,
+        assembly_context: AssemblyContext,
+        note_script_programs: Vec<CodeBlock>,
+        tx_script_program: Option<CodeBlock>,
+    ) -> Result<Program, TransactionCompilerError> {
+        // Create [CodeBlockTable] from [AssemblyContext]
+        let mut cb_table = self
+            .assembler
+            .build_cb_table(assembly_context)
+            .map_err(TransactionCompilerError::BuildCodeBlockTableFailed)?;
+
+        // insert note roots into [CodeBlockTable]
+        note_script_programs.into_iter().for_each(|note_root| {
+            cb_table.insert(note_root);
+        });
+
+        // insert transaction script into [CodeBlockTable]
+        if let Some(tx_script_program) = tx_script_program {
+            cb_table.insert(tx_script_program);
+        }
+
+        // Create transaction program with kernel
+        let program = Program::with_kernel(
+            self.kernel_main.clone(),
+            self.assembler.kernel().clone(),
+            cb_table,
+        );
+
+        // Create compiled transaction
+        Ok(program)
+    }
+
+    /// Checks that the compiled transaction script is compatible with the target account
+    /// interfaces and returns it.
+    fn verify_tx_script(
+        &self,
+        compiled: Result<(TransactionScript, CodeBlock), TransactionScriptError>,
+        target_account_proc: Vec<ScriptTarget>,
+    ) -> Result<TransactionScript, TransactionCompilerError> {
+        let (tx_script, code_block) = compiled.map_err(|e| match e {
+            TransactionScriptError::ScriptCompilationError(asm_error) => {
+                TransactionCompilerError::CompileTxScriptFailed(asm_error)
+            },
+        })?;
+        self.verify_tx_script_code(&code_block, target_account_proc)?;
+        Ok(tx_script)
+    }
+
+    /// Compiles the provided transaction script into a [CodeBlock] without checking its
+    /// compatibility with any account interface.
+    pub(crate) fn compile_tx_script_code(
+        &self,
+        tx_script_ast: &ProgramAst,
+    ) -> Result<CodeBlock, TransactionCompilerError> {
+        self.assembler
+            .compile_in_context(
+                tx_script_ast,
+                &mut AssemblyContext::for_program(Some(tx_script_ast)),
+            )
+            .map_err(TransactionCompilerError::CompileTxScriptFailed)
+    }
+
+    /// Checks that the compiled transaction script is compatible with the target account
+    /// interfaces.
+    pub(crate) fn verify_tx_script_code(
+        &self,
+        code_block: &CodeBlock,
+        target_account_proc: Vec<ScriptTarget>,
+    ) -> Result<(), TransactionCompilerError> {
+        for target in target_account_proc.into_iter() {
+            verify_program_account_compatibility(
+                code_block,
+                &self.get_target_interface(target)?,
+                &self.account_procedures,
+                ScriptType::TransactionScript,
+            )?;
+        }
+        Ok(())
+    }
+
     /// Returns a [CodeBlock] of the compiled transaction script program.
     ///
     /// The transaction script compatibility is verified against the target account interface.
@@ -237,7 +609,7 @@ impl TransactionCompiler {
         &self,
         tx_script: &ProgramAst,
         assembly_context: &mut AssemblyContext,
-        target_account_interface: Vec<Digest>,
+        target_account_interface: &[Digest],
     ) -> Result<CodeBlock, TransactionCompilerError> {
         let tx_script_code_block = self
             .assembler
@@ -245,7 +617,8 @@ impl TransactionCompiler {
             .map_err(TransactionCompilerError::CompileTxScriptFailed)?;
         verify_program_account_compatibility(
             &tx_script_code_block,
-            &target_account_interface,
+            target_account_interface,
+            &self.account_procedures,
             ScriptType::TransactionScript,
         )?;
         Ok(tx_script_code_block)
@@ -285,20 +658,31 @@ impl Default for TransactionCompiler {
 /// This is achieved by checking that at least one execution branch in the program is compatible
 /// with the target account interface.
 ///
+/// Call targets which are not a part of the target account interface are classified using the
+/// interfaces of all accounts known to the compiler: a call to a procedure of another known
+/// account is reported as a foreign account call, while a call to an unknown procedure is reported
+/// as an incompatibility with the target account interface. Syscalls are not considered.
+///
 /// # Errors
 /// Returns an error if the program is not compatible with the target account interface.
 fn verify_program_account_compatibility(
     program: &CodeBlock,
     target_account_interface: &[Digest],
+    known_account_interfaces: &BTreeMap<AccountId, Vec<Digest>>,
     script_type: ScriptType,
 ) -> Result<(), TransactionCompilerError> {
-    // collect call branches
-    let branches = collect_call_branches(program);
+    let report = CompatibilityReport::new(program, target_account_interface);
 
     // if none of the branches are compatible with the target account, return an error
-    if !branches.iter().any(|call_targets| {
-        call_targets.iter().all(|target| target_account_interface.contains(target))
-    }) {
+    if !report.is_compatible() {
+        let foreign_call =
+            report.missing_targets().iter().flatten().find(|target| {
+                known_account_interfaces.values().any(|procs| procs.contains(target))
+            });
+        if let Some(target) = foreign_call {
+            return Err(TransactionCompilerError::ForeignAccountCall(*target));
+        }
+
         return match script_type {
             ScriptType::NoteScript => {
                 Err(TransactionCompilerError::NoteIncompatibleWithAccountInterface(program.hash()))
@@ -312,50 +696,45 @@ fn verify_program_account_compatibility(
     Ok(())
 }
 
-/// Collect call branches by recursively traversing through program execution branches and
-/// accumulating call targets.
-fn collect_call_branches(code_block: &CodeBlock) -> Vec<Vec<Digest>> {
-    let mut branches = vec![vec![]];
-    recursively_collect_call_branches(code_block, &mut branches);
-    branches
+// COMPATIBILITY REPORT
+// ================================================================================================
+
+/// Describes the compatibility of a program with an account interface.
+///
+/// For each execution branch of the program, the report contains the call targets of the branch
+/// which are not a part of the account interface. Syscalls are not considered, while proxy blocks
+/// are treated as call targets (see [collect_call_branches()]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompatibilityReport {
+    missing_targets: Vec<Vec<Digest>>,
 }
 
-/// Generates a list of calls invoked in each execution branch of the provided code block.
-fn recursively_collect_call_branches(code_block: &CodeBlock, branches: &mut Vec<Vec<Digest>>) {
-    match code_block {
-        CodeBlock::Join(block) => {
-            recursively_collect_call_branches(block.first(), branches);
-            recursively_collect_call_branches(block.second(), branches);
-        },
-        CodeBlock::Split(block) => {
-            let current_len = branches.last().expect("at least one execution branch").len();
-            recursively_collect_call_branches(block.on_false(), branches);
-
-            // If the previous branch had additional calls we need to create a new branch
-            if branches.last().expect("at least one execution branch").len() > current_len {
-                branches.push(
-                    branches.last().expect("at least one execution branch")[..current_len].to_vec(),
-                );
-            }
+impl CompatibilityReport {
+    /// Returns a new [CompatibilityReport] for the provided program and account interface.
+    fn new(program: &CodeBlock, account_interface: &[Digest]) -> Self {
+        let missing_targets = collect_call_branches(program)
+            .into_iter()
+            .map(|call_targets| {
+                call_targets
+                    .into_iter()
+                    .filter(|target| !account_interface.contains(target))
+                    .collect()
+            })
+            .collect();
+
+        Self { missing_targets }
+    }
 
-            recursively_collect_call_branches(block.on_true(), branches);
-        },
-        CodeBlock::Loop(block) => {
-            recursively_collect_call_branches(block.body(), branches);
-        },
-        CodeBlock::Call(block) => {
-            if block.is_syscall() {
-                return;
-            }
+    /// Returns true if at least one execution branch of the program only calls procedures of the
+    /// account interface.
+    pub fn is_compatible(&self) -> bool {
+        self.missing_targets.iter().any(|targets| targets.is_empty())
+    }
 
-            branches
-                .last_mut()
-                .expect("at least one execution branch")
-                .push(block.fn_hash());
-        },
-        CodeBlock::Span(_) => {},
-        CodeBlock::Proxy(_) => {},
-        CodeBlock::Dyn(_) => {},
+    /// Returns the call targets of each execution branch which are not a part of the account
+    /// interface.
+    pub fn missing_targets(&self) -> &[Vec<Digest>] {
+        &self.missing_targets
     }
 }
 
@@ -373,6 +752,102 @@ pub enum ScriptTarget {
     Procedures(Vec<Digest>),
 }
 
+// TRANSACTION INTENT
+// ================================================================================================
+
+/// A high-level description of the outcome of a transaction.
+///
+/// An intent is translated into input notes and a transaction script built with the well-known
+/// script builders of the Miden library (see [TransactionCompiler::compile_intent()]). The
+/// transaction script of every intent is authenticated via `auth_tx_rpo_falcon512` of the basic
+/// authentication contract, which must be exported by the executing account.
+#[derive(Clone, Debug)]
+pub enum Intent {
+    /// Send `asset` to the account `to` via an off-chain P2ID note with the specified serial
+    /// number; the note tag is built as described in [NoteTag::for_account()]. The executing
+    /// account must expose the basic wallet interface.
+    Send {
+        to: AccountId,
+        asset: Asset,
+        serial_num: Word,
+    },
+    /// Consume `note` against the executing account.
+    Receive { note: Box<InputNote> },
+    /// Replace the authentication key of the executing account with `new_key`; see
+    /// [rotate_auth_key_script()] for the requirements on the `set_item_root` procedure.
+    RotateKey {
+        new_key: PublicKey,
+        set_item_root: Digest,
+    },
+}
+
+impl Intent {
+    /// Returns the notes consumed by a transaction carrying out this intent.
+    pub fn input_notes(&self) -> InputNotes {
+        match self {
+            Self::Receive { note } => InputNotes::new(vec![note.as_ref().clone()])
+                .expect("a single note is a valid note set"),
+            Self::Send { .. } | Self::RotateKey { .. } => InputNotes::default(),
+        }
+    }
+
+    /// Returns the transaction script of a transaction carrying out this intent.
+    ///
+    /// # Errors
+    /// Returns an error if the recipient or the tag of the note created by a [Intent::Send] cannot
+    /// be built.
+    pub fn tx_script(&self) -> Result<ProgramAst, TransactionCompilerError> {
+        let script = match self {
+            Self::Send { to, asset, serial_num } => {
+                let inputs = NoteInputs::new(vec![(*to).into()])
+                    .map_err(TransactionCompilerError::NoteScriptError)?;
+                let recipient =
+                    build_recipient(p2id_note_script().hash(), *serial_num, inputs.commitment());
+                let tag = NoteTag::for_account(*to, NoteType::OffChain)
+                    .map_err(TransactionCompilerError::NoteScriptError)?;
+                format!(
+                    "\
+                    use.miden::contracts::auth::basic->auth_tx
+                    use.miden::contracts::wallets::basic->wallet
+
+                    begin
+                        push.{recipient}
+                        push.{tag}
+                        push.{asset}
+                        call.wallet::send_asset drop
+                        dropw dropw
+                        call.auth_tx::auth_tx_rpo_falcon512
+                    end
+                    ",
+                    recipient = word_to_masm(recipient.into()),
+                    tag = tag.inner(),
+                    asset = word_to_masm((*asset).into()),
+                )
+            },
+            Self::Receive { .. } => "\
+                use.miden::contracts::auth::basic->auth_tx
+
+                begin
+                    call.auth_tx::auth_tx_rpo_falcon512
+                end
+                "
+            .to_string(),
+            Self::RotateKey { new_key, set_item_root } => {
+                return Ok(rotate_auth_key_script(*set_item_root, *new_key));
+            },
+        };
+        Ok(ProgramAst::parse(&script).expect("intent transaction script is valid"))
+    }
+}
+
+/// Returns the MASM representation of the provided word, as expected by the `push` instruction.
+fn word_to_masm(word: Word) -> String {
+    word.iter()
+        .map(|felt| format!("{}", felt.as_int()))
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
 // SCRIPT TYPE
 // ================================================================================================
 
@@ -1,12 +1,19 @@
+use core::ops::ControlFlow;
+
 use miden_objects::{
     accounts::ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+    assembly::{LibraryNamespace, LibraryPath, MaslLibrary, Module, Version},
     assets::{Asset, FungibleAsset},
     notes::{Note, NoteInclusionProof},
-    transaction::{InputNote, InputNotes},
-    Felt, FieldElement, Word,
+    transaction::{canonical_note_order, InputNote, InputNotes},
+    AccountError, Felt, FieldElement, TransactionInputError, Word,
 };
+use mock::mock::account::mock_account_code;
 
-use super::{AccountId, ModuleAst, ProgramAst, ScriptTarget, TransactionCompiler};
+use super::{
+    AccountId, CodeBlock, CompatibilityReport, Digest, ModuleAst, ProgramAst, ScriptTarget,
+    TransactionCompiler, TransactionCompilerError, TransactionKernel,
+};
 
 // CONSTANTS
 // ================================================================================================
@@ -58,6 +65,109 @@ fn test_load_account() {
     }
 }
 
+#[test]
+fn test_load_account_code() {
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+    let account_code_ast = ModuleAst::parse(ACCOUNT_CODE_MASM).unwrap();
+
+    let mut tx_compiler = TransactionCompiler::new();
+    let account_code = tx_compiler.load_account(account_id, account_code_ast).unwrap();
+
+    // loading the precompiled code yields the same interface as compiling it from source
+    let mut precompiled_tx_compiler = TransactionCompiler::new();
+    precompiled_tx_compiler.load_account_code(account_id, account_code);
+    assert_eq!(
+        precompiled_tx_compiler.interface_for(account_id),
+        tx_compiler.interface_for(account_id)
+    );
+    for proc_name in ["account_procedure_1", "account_procedure_2"] {
+        let proc_root = precompiled_tx_compiler.resolve_procedure(account_id, proc_name);
+        assert!(proc_root.is_some());
+        assert_eq!(proc_root, tx_compiler.resolve_procedure(account_id, proc_name));
+    }
+}
+
+#[test]
+fn test_verify_account_code() {
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+    let mut tx_compiler = TransactionCompiler::new();
+    let account_code = mock_account_code(&TransactionKernel::assembler());
+
+    // the account must be loaded first
+    assert!(matches!(
+        tx_compiler.verify_account_code(account_id, &account_code),
+        Err(TransactionCompilerError::AccountInterfaceNotFound(_))
+    ));
+
+    // an interface matching the code is accepted regardless of procedure order
+    let mut procedures = account_code.procedures().to_vec();
+    procedures.reverse();
+    tx_compiler.load_account_interface(account_id, procedures.clone()).unwrap();
+    assert!(tx_compiler.verify_account_code(account_id, &account_code).is_ok());
+
+    // an interface of a stale version of the code is detected
+    procedures.pop();
+    tx_compiler.load_account_interface(account_id, procedures).unwrap();
+    assert!(matches!(
+        tx_compiler.verify_account_code(account_id, &account_code),
+        Err(TransactionCompilerError::AccountInterfaceMismatch(id, root))
+            if id == account_id && root == account_code.root()
+    ));
+
+    // once different code is loaded for the account, the previous code no longer matches
+    let account_code_ast = ModuleAst::parse(ACCOUNT_CODE_MASM).unwrap();
+    let other_account_code = tx_compiler.load_account(account_id, account_code_ast).unwrap();
+    assert!(tx_compiler.verify_account_code(account_id, &other_account_code).is_ok());
+    assert!(tx_compiler.verify_account_code(account_id, &account_code).is_err());
+}
+
+#[test]
+fn test_load_account_without_exported_procedures() {
+    let mut tx_compiler = TransactionCompiler::new();
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+
+    // a module whose procedures are not exported is rejected when the account is loaded
+    let account_code_ast = ModuleAst::parse("proc.foo push.1 drop end").unwrap();
+    assert!(matches!(
+        tx_compiler.load_account(account_id, account_code_ast),
+        Err(TransactionCompilerError::LoadAccountFailed(
+            AccountError::AccountCodeNoProcedures
+        ))
+    ));
+    assert!(tx_compiler.interface_for(account_id).is_none());
+}
+
+#[test]
+fn test_load_account_interface() {
+    let mut tx_compiler = TransactionCompiler::new();
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+    let proc_1 = Digest::try_from(ACCT_PROC_1).unwrap();
+    let proc_2 = Digest::try_from(ACCT_PROC_2).unwrap();
+
+    // an empty interface is rejected
+    assert!(matches!(
+        tx_compiler.load_account_interface(account_id, vec![]),
+        Err(TransactionCompilerError::AccountInterfaceEmpty(id)) if id == account_id
+    ));
+
+    // an interface with duplicate procedures is rejected
+    assert!(matches!(
+        tx_compiler.load_account_interface(account_id, vec![proc_1, proc_2, proc_1]),
+        Err(TransactionCompilerError::AccountInterfaceDuplicateProcedure(id, proc))
+            if id == account_id && proc == proc_1
+    ));
+    assert_eq!(tx_compiler.interface_for(account_id), None);
+
+    // a valid interface is registered and can be read back
+    let old_interface = tx_compiler.load_account_interface(account_id, vec![proc_1, proc_2]);
+    assert_eq!(old_interface.unwrap(), None);
+    assert_eq!(tx_compiler.interface_for(account_id), Some([proc_1, proc_2].as_slice()));
+}
+
 #[test]
 fn test_compile_valid_note_script() {
     let test_cases = [
@@ -178,6 +288,209 @@ fn mock_consumed_notes(
     vec![note_1, note_2]
 }
 
+#[test]
+fn test_compile_note_script_with_foreign_account_call() {
+    let mut tx_compiler = TransactionCompiler::new();
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+    let account_code_ast = ModuleAst::parse(ACCOUNT_CODE_MASM).unwrap();
+    tx_compiler.load_account(account_id, account_code_ast).unwrap();
+    let target_account_proc = ScriptTarget::AccountId(account_id);
+
+    let foreign_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN + 1).unwrap();
+    let account_code_ast = ModuleAst::parse(ADDITIONAL_PROCEDURES).unwrap();
+    tx_compiler.load_account(foreign_account_id, account_code_ast).unwrap();
+
+    // a call to a procedure of another known account is reported as a foreign call
+    let note_script_src = format!("begin call.{ACCT_PROC_1} call.{ADD_PROC_1} end");
+    let note_script_ast = ProgramAst::parse(note_script_src.as_str()).unwrap();
+    let result =
+        tx_compiler.compile_note_script(note_script_ast, vec![target_account_proc.clone()]);
+    let add_proc_1 = Digest::try_from(ADD_PROC_1).unwrap();
+    assert!(matches!(
+        result,
+        Err(TransactionCompilerError::ForeignAccountCall(digest)) if digest == add_proc_1
+    ));
+
+    // a call to a procedure which is not known to the compiler is reported as an incompatibility
+    tx_compiler
+        .load_account_interface(foreign_account_id, vec![Digest::try_from(ADD_PROC_2).unwrap()])
+        .unwrap();
+    let note_script_src = format!("begin call.{ADD_PROC_1} end");
+    let note_script_ast = ProgramAst::parse(note_script_src.as_str()).unwrap();
+    let result = tx_compiler.compile_note_script(note_script_ast, vec![target_account_proc]);
+    assert!(matches!(
+        result,
+        Err(TransactionCompilerError::NoteIncompatibleWithAccountInterface(_))
+    ));
+}
+
+#[test]
+fn test_compatibility_report_counts_proxies() {
+    let call_target = Digest::try_from(ACCT_PROC_1).unwrap();
+    let proxy_target = Digest::try_from(ADD_PROC_1).unwrap();
+    let program =
+        CodeBlock::new_join([CodeBlock::new_call(call_target), CodeBlock::new_proxy(proxy_target)]);
+
+    // the proxied procedure is a required member of the account interface
+    let report = CompatibilityReport::new(&program, &[call_target]);
+    assert!(!report.is_compatible());
+    assert_eq!(report.missing_targets(), &[vec![proxy_target]]);
+
+    let report = CompatibilityReport::new(&program, &[call_target, proxy_target]);
+    assert!(report.is_compatible());
+}
+
+#[test]
+fn test_analyze_note_agrees_with_compile_note_script() {
+    let note_scripts = [
+        "begin push.1 drop end".to_string(),
+        format!("begin call.{ACCT_PROC_1} call.{ACCT_PROC_2} end"),
+        format!("begin call.{ACCT_PROC_1} call.{ADD_PROC_1} end"),
+        format!(
+            "begin
+                if.true
+                    call.{ACCT_PROC_1}
+                else
+                    call.{ADD_PROC_2}
+                end
+            end"
+        ),
+        format!(
+            "begin
+                call.{ACCT_PROC_1}
+                if.true
+                    call.{ADD_PROC_1}
+                else
+                    call.{ADD_PROC_2}
+                end
+            end"
+        ),
+    ];
+
+    let mut tx_compiler = TransactionCompiler::new();
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+    let account_code_ast = ModuleAst::parse(ACCOUNT_CODE_MASM).unwrap();
+    tx_compiler.load_account(account_id, account_code_ast).unwrap();
+    let target_account_proc = ScriptTarget::AccountId(account_id);
+
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN + 1).unwrap();
+    let account_code_ast = ModuleAst::parse(ADDITIONAL_PROCEDURES).unwrap();
+    tx_compiler.load_account(account_id, account_code_ast).unwrap();
+
+    for note_script_src in note_scripts {
+        let note_script_ast = ProgramAst::parse(note_script_src.as_str()).unwrap();
+        let note_script = tx_compiler.compile_note_script(note_script_ast.clone(), vec![]).unwrap();
+
+        let report = tx_compiler.analyze_note(&note_script, target_account_proc.clone()).unwrap();
+        let result =
+            tx_compiler.compile_note_script(note_script_ast, vec![target_account_proc.clone()]);
+        assert_eq!(report.is_compatible(), result.is_ok());
+    }
+
+    // the report lists the call targets which are not a part of the account interface
+    let note_script_src = format!("begin call.{ACCT_PROC_1} call.{ADD_PROC_1} end");
+    let note_script_ast = ProgramAst::parse(note_script_src.as_str()).unwrap();
+    let note_script = tx_compiler.compile_note_script(note_script_ast, vec![]).unwrap();
+    let report = tx_compiler.analyze_note(&note_script, target_account_proc).unwrap();
+    assert_eq!(report.missing_targets(), &[vec![Digest::try_from(ADD_PROC_1).unwrap()]]);
+}
+
+#[test]
+fn test_is_note_compatible() {
+    let mut tx_compiler = TransactionCompiler::new();
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+    let account_code_ast = ModuleAst::parse(ACCOUNT_CODE_MASM).unwrap();
+    tx_compiler.load_account(account_id, account_code_ast).unwrap();
+
+    let other_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN + 1).unwrap();
+    let account_code_ast = ModuleAst::parse(ADDITIONAL_PROCEDURES).unwrap();
+    tx_compiler.load_account(other_account_id, account_code_ast).unwrap();
+
+    let compile = |source: String| {
+        tx_compiler
+            .compile_note_script(ProgramAst::parse(&source).unwrap(), vec![])
+            .unwrap()
+    };
+
+    // compatible
+    let note_script = compile(format!("begin call.{ACCT_PROC_1} call.{ACCT_PROC_2} end"));
+    assert!(tx_compiler.is_note_compatible(&note_script, account_id).unwrap());
+
+    // incompatible
+    let note_script = compile(format!("begin call.{ACCT_PROC_1} call.{ADD_PROC_1} end"));
+    assert!(!tx_compiler.is_note_compatible(&note_script, account_id).unwrap());
+
+    // calls to procedures of another known account are incompatible as well
+    assert!(!tx_compiler.is_note_compatible(&note_script, other_account_id).unwrap());
+
+    // unknown account
+    let unknown_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN + 2).unwrap();
+    assert!(matches!(
+        tx_compiler.is_note_compatible(&note_script, unknown_account_id),
+        Err(TransactionCompilerError::AccountInterfaceNotFound(id)) if id == unknown_account_id
+    ));
+}
+
+#[test]
+fn test_migrate_account_code() {
+    let mut tx_compiler = TransactionCompiler::new();
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+
+    // the account interface must be known before it can be migrated
+    let new_code = "\
+export.account_procedure_1
+    push.1.2
+    add
+end
+
+export.additional_procedure_1
+    push.3.4
+    add
+end
+";
+    assert!(matches!(
+        tx_compiler.migrate_account_code(account_id, ModuleAst::parse(new_code).unwrap()),
+        Err(TransactionCompilerError::AccountInterfaceNotFound(id)) if id == account_id
+    ));
+
+    let account_code_ast = ModuleAst::parse(ACCOUNT_CODE_MASM).unwrap();
+    tx_compiler.load_account(account_id, account_code_ast).unwrap();
+
+    // account_procedure_1 is retained by the migration while account_procedure_2 is removed
+    let old_interface = tx_compiler.interface_for(account_id).unwrap().to_vec();
+    let removed = tx_compiler
+        .migrate_account_code(account_id, ModuleAst::parse(new_code).unwrap())
+        .unwrap();
+    assert_eq!(removed.len(), 1);
+
+    let new_interface = tx_compiler.interface_for(account_id).unwrap();
+    assert_eq!(new_interface.len(), 2);
+    assert!(!new_interface.contains(&removed[0]));
+
+    let retained = old_interface.iter().find(|proc| **proc != removed[0]).unwrap();
+    assert!(new_interface.contains(retained));
+
+    let compile = |proc: &Digest| {
+        let source = format!("begin call.{} end", proc.to_hex());
+        tx_compiler
+            .compile_note_script(ProgramAst::parse(&source).unwrap(), vec![])
+            .unwrap()
+    };
+    let retained_note = compile(retained);
+    let removed_note = compile(&removed[0]);
+
+    assert!(tx_compiler.is_note_compatible(&retained_note, account_id).unwrap());
+    assert!(!tx_compiler.is_note_compatible(&removed_note, account_id).unwrap());
+}
+
 #[test]
 fn test_transaction_compilation_succeeds() {
     let mut tx_compiler = TransactionCompiler::new();
@@ -186,21 +499,7 @@ fn test_transaction_compilation_succeeds() {
     let account_code_ast = ModuleAst::parse(ACCOUNT_CODE_MASM).unwrap();
     let _account_code = tx_compiler.load_account(account_id, account_code_ast).unwrap();
 
-    let notes = mock_consumed_notes(&mut tx_compiler, account_id);
-    let mock_inclusion_proof = NoteInclusionProof::new(
-        Default::default(),
-        Default::default(),
-        Default::default(),
-        0,
-        Default::default(),
-    )
-    .unwrap();
-    let notes = notes
-        .into_iter()
-        .map(|note| InputNote::new(note, mock_inclusion_proof.clone()))
-        .collect::<Vec<_>>();
-
-    let notes = InputNotes::new(notes).unwrap();
+    let notes = mock_input_notes(&mut tx_compiler, account_id);
 
     let tx_script_src = format!("begin call.{ACCT_PROC_2} end");
     let tx_script_ast = ProgramAst::parse(tx_script_src.as_str()).unwrap();
@@ -209,6 +508,202 @@ fn test_transaction_compilation_succeeds() {
     assert!(res.is_ok());
 }
 
+#[test]
+fn test_transaction_compilation_enforces_canonical_note_order() {
+    let mut tx_compiler = TransactionCompiler::new().with_canonical_note_order();
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+    let account_code_ast = ModuleAst::parse(ACCOUNT_CODE_MASM).unwrap();
+    let _account_code = tx_compiler.load_account(account_id, account_code_ast).unwrap();
+
+    let mut notes = mock_input_notes(&mut tx_compiler, account_id).into_iter().collect::<Vec<_>>();
+
+    // arrange the notes in non-canonical order
+    canonical_note_order(&mut notes);
+    notes.reverse();
+    let input_notes = InputNotes::new(notes.clone()).unwrap();
+    assert!(matches!(
+        tx_compiler.compile_transaction(account_id, &input_notes, None),
+        Err(TransactionCompilerError::InputNotesNotInCanonicalOrder)
+    ));
+
+    canonical_note_order(&mut notes);
+    let input_notes = InputNotes::new(notes).unwrap();
+    assert!(tx_compiler.compile_transaction(account_id, &input_notes, None).is_ok());
+}
+
+#[test]
+fn test_transaction_compilation_rejects_duplicate_consumed_notes() {
+    let mut tx_compiler = TransactionCompiler::new();
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+    let account_code_ast = ModuleAst::parse(ACCOUNT_CODE_MASM).unwrap();
+    let _account_code = tx_compiler.load_account(account_id, account_code_ast).unwrap();
+
+    let mut notes = mock_input_notes(&mut tx_compiler, account_id).into_iter().collect::<Vec<_>>();
+
+    // notes with the same nullifier cannot be consumed by the same transaction, so they are
+    // rejected before they can be passed to the compiler
+    let nullifier = notes[0].note().nullifier().inner();
+    notes.push(notes[0].clone());
+    assert!(matches!(
+        InputNotes::new(notes),
+        Err(TransactionInputError::DuplicateInputNote(digest)) if digest == nullifier
+    ));
+}
+
+#[test]
+fn test_transaction_compilation_is_independent_of_note_order() {
+    let mut tx_compiler = TransactionCompiler::new();
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+    let account_code_ast = ModuleAst::parse(ACCOUNT_CODE_MASM).unwrap();
+    let _account_code = tx_compiler.load_account(account_id, account_code_ast).unwrap();
+
+    let mut notes = mock_input_notes(&mut tx_compiler, account_id).into_iter().collect::<Vec<_>>();
+
+    let tx_script_src = format!("begin call.{ACCT_PROC_2} end");
+    let tx_script_ast = ProgramAst::parse(tx_script_src.as_str()).unwrap();
+
+    let input_notes = InputNotes::new(notes.clone()).unwrap();
+    let program = tx_compiler
+        .compile_transaction(account_id, &input_notes, Some(&tx_script_ast))
+        .unwrap();
+
+    notes.reverse();
+    let input_notes = InputNotes::new(notes).unwrap();
+    let reversed_program = tx_compiler
+        .compile_transaction(account_id, &input_notes, Some(&tx_script_ast))
+        .unwrap();
+
+    // the code block table is keyed by block hash, so its contents do not depend on the order in
+    // which the blocks were inserted
+    assert_eq!(program.hash(), reversed_program.hash());
+    assert_eq!(program.kernel(), reversed_program.kernel());
+    for note in input_notes.iter() {
+        assert!(program.cb_table().has(note.note().script().hash()));
+    }
+    assert_eq!(format!("{program:?}"), format!("{reversed_program:?}"));
+}
+
+#[test]
+fn test_transaction_compilation_reports_progress() {
+    let mut tx_compiler = TransactionCompiler::new();
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+    let account_code_ast = ModuleAst::parse(ACCOUNT_CODE_MASM).unwrap();
+    let _account_code = tx_compiler.load_account(account_id, account_code_ast).unwrap();
+
+    let input_notes = mock_input_notes(&mut tx_compiler, account_id);
+
+    // the callback fires once per note
+    let mut reports = Vec::new();
+    let res = tx_compiler.compile_transaction_with_progress(
+        account_id,
+        &input_notes,
+        None,
+        &mut |done, total| {
+            reports.push((done, total));
+            ControlFlow::Continue(())
+        },
+    );
+    assert!(res.is_ok());
+    assert_eq!(reports, vec![(1, 2), (2, 2)]);
+
+    // compilation stops once the callback breaks
+    let mut num_reports = 0;
+    let res = tx_compiler.compile_transaction_with_progress(
+        account_id,
+        &input_notes,
+        None,
+        &mut |_, _| {
+            num_reports += 1;
+            ControlFlow::Break(())
+        },
+    );
+    assert!(matches!(res, Err(TransactionCompilerError::CompilationCancelled)));
+    assert_eq!(num_reports, 1);
+}
+
+#[test]
+fn test_resolve_procedure() {
+    let mut tx_compiler = TransactionCompiler::new();
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+    let account_code = mock_account_code(&TransactionKernel::assembler());
+
+    // procedures cannot be resolved before the account is loaded
+    assert_eq!(tx_compiler.resolve_procedure(account_id, "create_note"), None);
+
+    tx_compiler.load_account(account_id, account_code.module().clone()).unwrap();
+
+    // local and re-exported procedures can be resolved
+    assert_eq!(
+        tx_compiler.resolve_procedure(account_id, "create_note"),
+        Some(account_code.procedures()[5])
+    );
+    assert_eq!(
+        tx_compiler.resolve_procedure(account_id, "receive_asset"),
+        Some(account_code.procedures()[0])
+    );
+    assert_eq!(tx_compiler.resolve_procedure(account_id, "unknown"), None);
+
+    // names are not known for accounts loaded via their interface only
+    tx_compiler
+        .load_account_interface(account_id, account_code.procedures().to_vec())
+        .unwrap();
+    assert_eq!(tx_compiler.resolve_procedure(account_id, "create_note"), None);
+}
+
+#[test]
+fn test_compile_note_script_with_libraries() {
+    let module_ast = ModuleAst::parse(
+        "\
+export.bar
+    push.1 drop
+end
+",
+    )
+    .unwrap();
+    let module = Module::new(LibraryPath::new("mylib::foo").unwrap(), module_ast);
+    let library = MaslLibrary::new(
+        LibraryNamespace::new("mylib").unwrap(),
+        Version::default(),
+        false,
+        vec![module],
+        vec![],
+    )
+    .unwrap();
+
+    let note_script_src = "\
+use.mylib::foo
+
+begin
+    exec.foo::bar
+end
+";
+    let note_script_ast = ProgramAst::parse(note_script_src).unwrap();
+
+    // the note script cannot be compiled without the library
+    let tx_compiler = TransactionCompiler::new();
+    assert!(matches!(
+        tx_compiler.compile_note_script(note_script_ast.clone(), vec![]),
+        Err(TransactionCompilerError::CompileNoteScriptFailed(_))
+    ));
+
+    // once the library is registered the note script compiles
+    let tx_compiler = TransactionCompiler::new()
+        .with_libraries(core::slice::from_ref(&library))
+        .unwrap();
+    assert!(tx_compiler.compile_note_script(note_script_ast, vec![]).is_ok());
+
+    // a library cannot be registered twice
+    assert!(matches!(
+        tx_compiler.with_libraries(&[library]),
+        Err(TransactionCompilerError::LoadLibraryFailed(_))
+    ));
+}
+
 // HELPERS
 // ================================================================================================
 
@@ -218,3 +713,20 @@ fn hex_to_bytes(hex: &str) -> Vec<u8> {
         .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
         .collect::<Vec<_>>()
 }
+
+/// Returns the notes built by [mock_consumed_notes()] as input notes with a mock inclusion proof.
+fn mock_input_notes(tx_compiler: &mut TransactionCompiler, account_id: AccountId) -> InputNotes {
+    let mock_inclusion_proof = NoteInclusionProof::new(
+        Default::default(),
+        Default::default(),
+        Default::default(),
+        0,
+        Default::default(),
+    )
+    .unwrap();
+    let notes = mock_consumed_notes(tx_compiler, account_id)
+        .into_iter()
+        .map(|note| InputNote::new(note, mock_inclusion_proof.clone()))
+        .collect::<Vec<_>>();
+    InputNotes::new(notes).unwrap()
+}
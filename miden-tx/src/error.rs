@@ -14,11 +14,18 @@ use crate::utils::string::*;
 
 #[derive(Debug)]
 pub enum TransactionCompilerError {
+    AccountInterfaceDuplicateProcedure(AccountId, Digest),
+    AccountInterfaceEmpty(AccountId),
+    AccountInterfaceMismatch(AccountId, Digest),
     AccountInterfaceNotFound(AccountId),
     BuildCodeBlockTableFailed(AssemblyError),
+    CompilationCancelled,
     CompileNoteScriptFailed(AssemblyError),
     CompileTxScriptFailed(AssemblyError),
+    ForeignAccountCall(Digest),
+    InputNotesNotInCanonicalOrder,
     LoadAccountFailed(AccountError),
+    LoadLibraryFailed(AssemblyError),
     NoteIncompatibleWithAccountInterface(Digest),
     NoteScriptError(NoteError),
     NoTransactionDriver,
@@ -39,6 +46,12 @@ impl std::error::Error for TransactionCompilerError {}
 
 #[derive(Debug)]
 pub enum TransactionExecutorError {
+    AccountNotSpecified,
+    BlockOutsideValidityWindow {
+        block_num: u32,
+        min_block: u32,
+        max_block: u32,
+    },
     CompileNoteScriptFailed(TransactionCompilerError),
     CompileTransactionScriptFailed(TransactionCompilerError),
     CompileTransactionFailed(TransactionCompilerError),
@@ -53,6 +66,7 @@ pub enum TransactionExecutorError {
         expected: Option<Felt>,
         actual: Option<Felt>,
     },
+    InvalidTransactionInputs(TransactionInputError),
     InvalidTransactionOutput(TransactionOutputError),
     LoadAccountFailed(TransactionCompilerError),
 }
@@ -72,6 +86,7 @@ impl std::error::Error for TransactionExecutorError {}
 #[derive(Debug)]
 pub enum TransactionProverError {
     ProveTransactionProgramFailed(ExecutionError),
+    InvalidTransactionInputs(TransactionInputError),
     InvalidTransactionOutput(TransactionOutputError),
 }
 
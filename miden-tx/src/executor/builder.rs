@@ -0,0 +1,132 @@
+use miden_lib::AuthScheme;
+use miden_objects::{
+    assembly::ProgramAst,
+    transaction::{ExecutedTransaction, TransactionArgs},
+    Felt, Word,
+};
+
+use super::{AccountId, DataStore, NoteId, TransactionExecutor, TransactionExecutorError};
+use crate::utils::collections::*;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Transaction script which authenticates the transaction using the basic authentication
+/// procedure of the account.
+const BASIC_AUTH_SCRIPT: &str = "
+    use.miden::contracts::auth::basic->auth_tx
+
+    begin
+        call.auth_tx::auth_tx_rpo_falcon512
+    end
+";
+
+// TRANSACTION BUILDER
+// ================================================================================================
+
+/// A builder for executing transactions with a [TransactionExecutor].
+///
+/// The builder collects the account, the notes to consume, an optional transaction script and the
+/// authentication data, and then loads the account, compiles the transaction script, and executes
+/// the transaction in a single [TransactionBuilder::build()] call. The lower-level methods of the
+/// [TransactionExecutor] remain available for use cases not covered by the builder.
+#[derive(Debug, Clone)]
+pub struct TransactionBuilder {
+    block_ref: u32,
+    account_id: Option<AccountId>,
+    note_ids: Vec<NoteId>,
+    tx_script: Option<ProgramAst>,
+    tx_script_inputs: Vec<(Word, Vec<Felt>)>,
+}
+
+impl TransactionBuilder {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new [TransactionBuilder] for a transaction executed against the block with the
+    /// specified number.
+    pub fn new(block_ref: u32) -> Self {
+        Self {
+            block_ref,
+            account_id: None,
+            note_ids: Vec::new(),
+            tx_script: None,
+            tx_script_inputs: Vec::new(),
+        }
+    }
+
+    // BUILDER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Sets the account against which the transaction is executed.
+    pub fn account(mut self, account_id: AccountId) -> Self {
+        self.account_id = Some(account_id);
+        self
+    }
+
+    /// Adds the note with the specified ID to the notes consumed by the transaction.
+    pub fn consume_note(mut self, note_id: NoteId) -> Self {
+        self.note_ids.push(note_id);
+        self
+    }
+
+    /// Sets the transaction script executed after the consumed notes.
+    pub fn with_script(mut self, tx_script: ProgramAst) -> Self {
+        self.tx_script = Some(tx_script);
+        self
+    }
+
+    /// Adds the key data for the specified authentication scheme to the transaction script inputs.
+    ///
+    /// For [AuthScheme::RpoFalcon512], `key_pair` is the serialized key pair which is placed into
+    /// the advice map under the public key of the scheme.
+    ///
+    /// If no transaction script is set, the transaction is authenticated by the basic
+    /// authentication procedure of the account. Otherwise, the transaction script is responsible
+    /// for invoking the authentication procedure.
+    pub fn with_auth(mut self, scheme: AuthScheme, key_pair: Vec<Felt>) -> Self {
+        let pub_key: Word = match scheme {
+            AuthScheme::RpoFalcon512 { pub_key } => pub_key.into(),
+        };
+        self.tx_script_inputs.push((pub_key, key_pair));
+        self
+    }
+
+    // BUILD
+    // --------------------------------------------------------------------------------------------
+
+    /// Loads the account into the provided executor, compiles the transaction script and executes
+    /// the transaction.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The account was not specified.
+    /// - The account could not be loaded into the executor.
+    /// - The transaction script could not be compiled.
+    /// - The transaction could not be executed.
+    pub fn build<D: DataStore>(
+        self,
+        executor: &mut TransactionExecutor<D>,
+    ) -> Result<ExecutedTransaction, TransactionExecutorError> {
+        let account_id = self.account_id.ok_or(TransactionExecutorError::AccountNotSpecified)?;
+        executor.load_account(account_id)?;
+
+        let tx_script = match self.tx_script {
+            Some(tx_script) => Some(tx_script),
+            None if !self.tx_script_inputs.is_empty() => {
+                Some(ProgramAst::parse(BASIC_AUTH_SCRIPT).expect("basic auth script is valid"))
+            },
+            None => None,
+        };
+
+        let tx_args = match tx_script {
+            Some(tx_script) => {
+                let tx_script =
+                    executor.compile_tx_script(tx_script, self.tx_script_inputs, vec![])?;
+                Some(TransactionArgs::with_tx_script(tx_script))
+            },
+            None => None,
+        };
+
+        executor.execute_transaction(account_id, self.block_ref, &self.note_ids, tx_args)
+    }
+}
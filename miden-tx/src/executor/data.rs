@@ -1,5 +1,8 @@
 use miden_objects::{
-    accounts::AccountId, assembly::ModuleAst, notes::NoteId, transaction::TransactionInputs,
+    accounts::AccountId,
+    assembly::ModuleAst,
+    notes::{NoteId, Nullifier},
+    transaction::{InputNote, TransactionInputs},
 };
 
 use crate::DataStoreError;
@@ -34,4 +37,16 @@ pub trait DataStore {
 
     /// Returns the account code [ModuleAst] associated with the the specified [AccountId].
     fn get_account_code(&self, account_id: AccountId) -> Result<ModuleAst, DataStoreError>;
+
+    /// Returns the recorded note with the specified nullifier, or None if the data store does not
+    /// contain such a note.
+    ///
+    /// The default implementation does not index notes by their nullifiers and always returns
+    /// None.
+    fn get_note_by_nullifier(
+        &self,
+        _nullifier: Nullifier,
+    ) -> Result<Option<InputNote>, DataStoreError> {
+        Ok(None)
+    }
 }
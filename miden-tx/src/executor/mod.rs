@@ -1,19 +1,22 @@
 use miden_lib::transaction::{ToTransactionKernelInputs, TransactionKernel};
 use miden_objects::{
-    assembly::ProgramAst,
-    transaction::{TransactionArgs, TransactionInputs, TransactionScript},
-    vm::{Program, StackOutputs},
-    Felt, Word, ZERO,
+    assembly::{AstSerdeOptions, ProgramAst},
+    transaction::{TransactionArgs, TransactionInputs, TransactionScript, TxScriptArgs},
+    vm::{CodeBlock, Program, StackOutputs},
+    Felt, Hasher, TransactionScriptError, Word, ZERO,
 };
 use vm_processor::ExecutionOptions;
 
 use super::{
     AccountCode, AccountId, Digest, ExecutedTransaction, NoteId, NoteScript, PreparedTransaction,
-    RecAdviceProvider, ScriptTarget, TransactionCompiler, TransactionExecutorError,
-    TransactionHost,
+    RecAdviceProvider, ScriptTarget, TransactionCompiler, TransactionCompilerError,
+    TransactionExecutorError, TransactionHost,
 };
 use crate::utils::collections::*;
 
+mod builder;
+pub use builder::TransactionBuilder;
+
 mod data;
 pub use data::DataStore;
 
@@ -37,6 +40,7 @@ pub struct TransactionExecutor<D: DataStore> {
     data_store: D,
     compiler: TransactionCompiler,
     exec_options: ExecutionOptions,
+    tx_script_cache: BTreeMap<Digest, CodeBlock>,
 }
 
 impl<D: DataStore> TransactionExecutor<D> {
@@ -48,6 +52,7 @@ impl<D: DataStore> TransactionExecutor<D> {
             data_store,
             compiler: TransactionCompiler::new(),
             exec_options: ExecutionOptions::default(),
+            tx_script_cache: BTreeMap::new(),
         }
     }
 
@@ -79,12 +84,17 @@ impl<D: DataStore> TransactionExecutor<D> {
     /// Loads the provided account interface (vector of procedure digests) into the compiler.
     ///
     /// Returns the old interface for the specified account ID if it previously existed.
+    ///
+    /// # Errors
+    /// Returns an error if the provided interface is empty or contains duplicate procedures.
     pub fn load_account_interface(
         &mut self,
         account_id: AccountId,
         procedures: Vec<Digest>,
-    ) -> Option<Vec<Digest>> {
-        self.compiler.load_account_interface(account_id, procedures)
+    ) -> Result<Option<Vec<Digest>>, TransactionExecutorError> {
+        self.compiler
+            .load_account_interface(account_id, procedures)
+            .map_err(TransactionExecutorError::LoadAccountFailed)
     }
 
     // COMPILERS
@@ -106,9 +116,64 @@ impl<D: DataStore> TransactionExecutor<D> {
     /// Compiles the provided transaction script source and inputs into a [TransactionScript] and
     /// checks (to the extent possible) that the transaction script can be executed against all
     /// accounts with the specified interfaces.
+    ///
+    /// Compiled scripts are cached by the hash of their source, so compiling a script identical
+    /// to a previously compiled one skips the assembly step. Values pushed onto the stack by the
+    /// script are a part of its source, so scripts which only differ in such values are compiled
+    /// separately; values passed via the script inputs do not affect caching. The cache can be
+    /// cleared via [TransactionExecutor::clear_script_cache()].
     pub fn compile_tx_script<T>(
+        &mut self,
+        tx_script_ast: ProgramAst,
+        inputs: T,
+        target_account_procs: Vec<ScriptTarget>,
+    ) -> Result<TransactionScript, TransactionExecutorError>
+    where
+        T: IntoIterator<Item = (Word, Vec<Felt>)>,
+    {
+        let source_hash = Hasher::hash(&tx_script_ast.to_bytes(AstSerdeOptions::new(false)));
+        let code_block = match self.tx_script_cache.get(&source_hash) {
+            Some(code_block) => code_block.clone(),
+            None => {
+                let code_block = self
+                    .compiler
+                    .compile_tx_script_code(&tx_script_ast)
+                    .map_err(TransactionExecutorError::CompileTransactionScriptFailed)?;
+                self.tx_script_cache.insert(source_hash, code_block.clone());
+                code_block
+            },
+        };
+
+        self.compiler
+            .verify_tx_script_code(&code_block, target_account_procs)
+            .map_err(TransactionExecutorError::CompileTransactionScriptFailed)?;
+
+        TransactionScript::from_parts(tx_script_ast, code_block.hash(), inputs).map_err(|err| {
+            match err {
+                TransactionScriptError::ScriptCompilationError(err) => {
+                    TransactionExecutorError::CompileTransactionScriptFailed(
+                        TransactionCompilerError::CompileTxScriptFailed(err),
+                    )
+                },
+            }
+        })
+    }
+
+    /// Removes all compiled transaction scripts from the cache used by
+    /// [TransactionExecutor::compile_tx_script()].
+    pub fn clear_script_cache(&mut self) {
+        self.tx_script_cache.clear();
+    }
+
+    /// Compiles the provided transaction script source, arguments and inputs into a
+    /// [TransactionScript] and checks (to the extent possible) that the transaction script can be
+    /// executed against all accounts with the specified interfaces.
+    ///
+    /// The code loading the arguments onto the stack is prepended to the script source.
+    pub fn compile_tx_script_with_args<T>(
         &self,
         tx_script_ast: ProgramAst,
+        args: TxScriptArgs,
         inputs: T,
         target_account_procs: Vec<ScriptTarget>,
     ) -> Result<TransactionScript, TransactionExecutorError>
@@ -116,7 +181,7 @@ impl<D: DataStore> TransactionExecutor<D> {
         T: IntoIterator<Item = (Word, Vec<Felt>)>,
     {
         self.compiler
-            .compile_tx_script(tx_script_ast, inputs, target_account_procs)
+            .compile_tx_script_with_args(tx_script_ast, args, inputs, target_account_procs)
             .map_err(TransactionExecutorError::CompileTransactionScriptFailed)
     }
 
@@ -133,7 +198,9 @@ impl<D: DataStore> TransactionExecutor<D> {
     /// # Errors:
     /// Returns an error if:
     /// - If required data can not be fetched from the [DataStore].
+    /// - If the reference block is outside of the validity window of the transaction.
     /// - If the transaction program can not be compiled.
+    /// - If any of the input notes does not have an inclusion proof.
     /// - If the transaction program can not be executed.
     pub fn execute_transaction(
         &self,
@@ -145,7 +212,9 @@ impl<D: DataStore> TransactionExecutor<D> {
         let transaction =
             self.prepare_transaction(account_id, block_ref, notes, tx_args.unwrap_or_default())?;
 
-        let (stack_inputs, advice_inputs) = transaction.get_kernel_inputs();
+        let (stack_inputs, advice_inputs) = transaction
+            .get_kernel_inputs()
+            .map_err(TransactionExecutorError::InvalidTransactionInputs)?;
         let advice_recorder: RecAdviceProvider = advice_inputs.into();
         let mut host = TransactionHost::new(transaction.account().into(), advice_recorder);
 
@@ -178,6 +247,7 @@ impl<D: DataStore> TransactionExecutor<D> {
     /// # Errors:
     /// Returns an error if:
     /// - If required data can not be fetched from the [DataStore].
+    /// - If the reference block is outside of the validity window of the transaction.
     /// - If the transaction can not be compiled.
     fn prepare_transaction(
         &self,
@@ -191,6 +261,17 @@ impl<D: DataStore> TransactionExecutor<D> {
             .get_transaction_inputs(account_id, block_ref, notes)
             .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?;
 
+        let block_num = tx_inputs.block_header().block_num();
+        if let Some((min_block, max_block)) = tx_args.validity_window() {
+            if !tx_args.is_valid_at(block_num) {
+                return Err(TransactionExecutorError::BlockOutsideValidityWindow {
+                    block_num,
+                    min_block,
+                    max_block,
+                });
+            }
+        }
+
         let tx_program = self
             .compiler
             .compile_transaction(
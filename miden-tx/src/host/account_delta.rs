@@ -5,7 +5,7 @@ use miden_objects::{
         AccountVaultDelta,
     },
     assets::{Asset, FungibleAsset, NonFungibleAsset},
-    Digest, Felt, Word, EMPTY_WORD, ZERO,
+    AccountError, Digest, Felt, StarkField, Word, EMPTY_WORD, ZERO,
 };
 use vm_processor::{ContextId, ProcessState};
 
@@ -64,6 +64,24 @@ impl AccountDeltaTracker {
 
         AccountDelta::new(storage_delta, vault_delta, nonce_delta).expect("invalid account delta")
     }
+
+    /// Adds the provided value to the tracked nonce increment.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The increment is zero.
+    /// - The incremented nonce does not fit into a field element.
+    pub fn increment_nonce(&mut self, value: Felt) -> Result<(), AccountError> {
+        // the tracked nonce never wraps around the field modulus, so this is the current nonce
+        let current = (self.init_nonce + self.nonce_delta).as_int();
+        let increment = value.as_int();
+        if increment == 0 || current as u128 + increment as u128 >= Felt::MODULUS as u128 {
+            return Err(AccountError::InvalidNonceIncrement { current, increment });
+        }
+
+        self.nonce_delta += value;
+        Ok(())
+    }
 }
 
 // EVENT HANDLERS
@@ -76,8 +94,9 @@ impl<A: AdviceProvider> TransactionHost<A> {
         process: &S,
     ) -> Result<(), TransactionKernelError> {
         let value = process.get_stack_item(0);
-        self.account_delta.nonce_delta += value;
-        Ok(())
+        self.account_delta
+            .increment_nonce(value)
+            .map_err(TransactionKernelError::InvalidNonceIncrement)
     }
 
     // ACCOUNT STORAGE UPDATE HANDLERS
@@ -15,10 +15,10 @@ use miden_objects::{
 use vm_processor::{ExecutionError, RecAdviceProvider};
 
 mod compiler;
-pub use compiler::{ScriptTarget, TransactionCompiler};
+pub use compiler::{CompatibilityReport, Intent, ScriptTarget, TransactionCompiler};
 
 mod executor;
-pub use executor::{DataStore, TransactionExecutor};
+pub use executor::{DataStore, TransactionBuilder, TransactionExecutor};
 
 pub mod host;
 pub use host::TransactionHost;
@@ -27,7 +27,7 @@ mod prover;
 pub use prover::{ProvingOptions, TransactionProver};
 
 mod verifier;
-pub use verifier::TransactionVerifier;
+pub use verifier::{transaction_public_inputs, TransactionPublicInputs, TransactionVerifier};
 
 mod error;
 pub use error::{
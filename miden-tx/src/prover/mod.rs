@@ -25,6 +25,15 @@ impl TransactionProver {
         Self { proof_options }
     }
 
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the options (e.g., security level and hash function) used by this prover to
+    /// generate transaction proofs.
+    pub fn proof_options(&self) -> &ProvingOptions {
+        &self.proof_options
+    }
+
     // TRANSACTION PROVER
     // --------------------------------------------------------------------------------------------
 
@@ -41,7 +50,9 @@ impl TransactionProver {
         let tx_witness: TransactionWitness = transaction.into();
 
         // extract required data from the transaction witness
-        let (stack_inputs, advice_inputs) = tx_witness.get_kernel_inputs();
+        let (stack_inputs, advice_inputs) = tx_witness
+            .get_kernel_inputs()
+            .map_err(TransactionProverError::InvalidTransactionInputs)?;
 
         let input_notes: InputNotes<Nullifier> = (tx_witness.tx_inputs().input_notes()).into();
 
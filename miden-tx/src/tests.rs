@@ -1,35 +1,53 @@
-use miden_lib::transaction::{ToTransactionKernelInputs, TransactionKernel};
+use miden_lib::{
+    notes::utils::set_storage_note_script,
+    transaction::{
+        memory::{
+            ACCT_DB_ROOT_PTR, BLOCK_METADATA_PTR, BLOCK_NUMBER_IDX, CHAIN_ROOT_PTR, NOTE_ROOT_PTR,
+            PREV_BLOCK_HASH_PTR,
+        },
+        ToTransactionKernelInputs, TransactionKernel,
+    },
+};
 use miden_objects::{
-    accounts::{Account, AccountCode},
+    accounts::{Account, AccountCode, AccountDelta},
     assembly::{Assembler, ModuleAst, ProgramAst},
-    assets::{Asset, FungibleAsset},
+    assets::{Asset, AssetVault, FungibleAsset},
     block::BlockHeader,
-    notes::NoteId,
+    notes::{aggregate_assets, Note, NoteId, NoteScript, Nullifier},
     transaction::{
-        ChainMmr, InputNote, InputNotes, ProvenTransaction, TransactionArgs, TransactionWitness,
+        check_asset_preservation, ChainMmr, InputNote, InputNotes, PreparedTransaction,
+        ProvenTransaction, TransactionArgs, TransactionWitness, TxScriptArgs,
     },
-    Felt, Word,
+    utils::collections::BTreeSet,
+    AssetError, Digest, Felt, TransactionInputError, Word, ZERO,
 };
 use miden_prover::ProvingOptions;
 use mock::{
     constants::{
-        non_fungible_asset, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
-        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+        non_fungible_asset, non_fungible_asset_2, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
+        ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_SENDER,
         ACCOUNT_PROCEDURE_INCR_NONCE_PROC_IDX, ACCOUNT_PROCEDURE_SET_CODE_PROC_IDX,
         ACCOUNT_PROCEDURE_SET_ITEM_PROC_IDX, FUNGIBLE_ASSET_AMOUNT, MIN_PROOF_SECURITY_LEVEL,
         STORAGE_INDEX_0,
     },
-    mock::{account::MockAccountType, notes::AssetPreservationStatus, transaction::mock_inputs},
+    mock::{
+        account::{mock_account, MockAccountType},
+        notes::{mock_note_with_assets, mock_notes, AssetPreservationStatus},
+        transaction::{mock_inputs, mock_inputs_with_existing},
+    },
+    run_tx,
     utils::prepare_word,
 };
 use vm_processor::{
     utils::{Deserializable, Serializable},
-    MemAdviceProvider,
+    ContextId, ExecutionError, MemAdviceProvider, ProcessState,
 };
 
 use super::{
-    AccountId, DataStore, DataStoreError, TransactionExecutor, TransactionHost, TransactionInputs,
-    TransactionProver, TransactionVerifier,
+    transaction_public_inputs, AccountId, DataStore, DataStoreError, ScriptTarget,
+    TransactionExecutor, TransactionExecutorError, TransactionHost, TransactionInputs,
+    TransactionProver, TransactionVerifier, TransactionVerifierError,
 };
 
 // TESTS
@@ -52,7 +70,7 @@ fn transaction_executor_witness() {
     let tx_witness: TransactionWitness = executed_transaction.clone().into();
 
     // use the witness to execute the transaction again
-    let (stack_inputs, advice_inputs) = tx_witness.get_kernel_inputs();
+    let (stack_inputs, advice_inputs) = tx_witness.get_kernel_inputs().unwrap();
     let mem_advice_provider: MemAdviceProvider = advice_inputs.into();
     let mut host = TransactionHost::new(tx_witness.account().into(), mem_advice_provider);
     let result =
@@ -68,6 +86,137 @@ fn transaction_executor_witness() {
     assert_eq!(executed_transaction.output_notes(), &tx_outputs.output_notes);
 }
 
+#[test]
+fn transaction_validity_window() {
+    let data_store = MockDataStore::default();
+    let mut executor = TransactionExecutor::new(data_store.clone());
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    // the reference block is within the window
+    let tx_args = TransactionArgs::default().with_validity_window(block_ref - 1, block_ref);
+    assert!(executor
+        .execute_transaction(account_id, block_ref, &note_ids, Some(tx_args))
+        .is_ok());
+
+    // the reference block is past the window
+    let tx_args = TransactionArgs::default().with_validity_window(0, block_ref - 1);
+    assert!(matches!(
+        executor.execute_transaction(account_id, block_ref, &note_ids, Some(tx_args)),
+        Err(TransactionExecutorError::BlockOutsideValidityWindow { block_num, min_block: 0, max_block })
+            if block_num == block_ref && max_block == block_ref - 1
+    ));
+
+    // the reference block is before the window
+    let tx_args = TransactionArgs::default().with_validity_window(block_ref + 1, block_ref + 10);
+    assert!(matches!(
+        executor.execute_transaction(account_id, block_ref, &note_ids, Some(tx_args)),
+        Err(TransactionExecutorError::BlockOutsideValidityWindow { .. })
+    ));
+}
+
+#[test]
+fn transaction_public_inputs_match_execution() {
+    let data_store = MockDataStore::default();
+    let mut executor = TransactionExecutor::new(data_store.clone());
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &note_ids, None).unwrap();
+
+    // assemble the public inputs from the claimed transaction results
+    let public_inputs = transaction_public_inputs(
+        executed_transaction.initial_account(),
+        executed_transaction.final_account().hash(),
+        executed_transaction.block_header().hash(),
+        executed_transaction.input_notes(),
+        executed_transaction.output_notes(),
+        executed_transaction.tx_args().tx_script().map(|script| *script.hash()),
+    );
+
+    // execute the transaction kernel and compare against the actual stack inputs and outputs
+    let tx_witness: TransactionWitness = executed_transaction.into();
+    let (stack_inputs, advice_inputs) = tx_witness.get_kernel_inputs().unwrap();
+    assert_eq!(public_inputs.stack_inputs().values(), stack_inputs.values());
+
+    let mem_advice_provider: MemAdviceProvider = advice_inputs.into();
+    let mut host = TransactionHost::new(tx_witness.account().into(), mem_advice_provider);
+    let result =
+        vm_processor::execute(tx_witness.program(), stack_inputs, &mut host, Default::default())
+            .unwrap();
+    assert_eq!(public_inputs.stack_outputs().stack(), result.stack_outputs().stack());
+}
+
+#[test]
+fn executed_transaction_block_commitments() {
+    let data_store = MockDataStore::default();
+    let mut executor = TransactionExecutor::new(data_store.clone());
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &note_ids, None).unwrap();
+
+    // re-run the executed program and read the block data the kernel prologue stored in memory
+    let prepared_tx = PreparedTransaction::new(
+        executed_transaction.program().clone(),
+        executed_transaction.tx_inputs().clone(),
+        executed_transaction.tx_args().clone(),
+    );
+    let process = run_tx(&prepared_tx).unwrap();
+    let read_mem = |addr| process.get_mem_value(ContextId::root(), addr).unwrap();
+
+    let block_commitments = executed_transaction.block_commitments();
+    assert_eq!(Word::from(block_commitments.prev_block_hash), read_mem(PREV_BLOCK_HASH_PTR));
+    assert_eq!(Word::from(block_commitments.chain_root), read_mem(CHAIN_ROOT_PTR));
+    assert_eq!(Word::from(block_commitments.state_root), read_mem(ACCT_DB_ROOT_PTR));
+    assert_eq!(Word::from(block_commitments.note_root), read_mem(NOTE_ROOT_PTR));
+    assert_eq!(
+        Felt::from(block_commitments.block_num),
+        read_mem(BLOCK_METADATA_PTR)[BLOCK_NUMBER_IDX]
+    );
+}
+
+#[test]
+fn executed_transaction_consumed_notes() {
+    let data_store = MockDataStore::default();
+    let mut executor = TransactionExecutor::new(data_store.clone());
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &note_ids, None).unwrap();
+
+    for (index, input_note) in data_store.notes.iter().enumerate() {
+        let note = input_note.note();
+        let consumed_note = executed_transaction.consumed_note(index as u32).unwrap();
+        assert_eq!(consumed_note.id, note.id());
+        assert_eq!(consumed_note.serial_num, note.serial_num());
+        assert_eq!(consumed_note.script_root, note.script().hash());
+        assert_eq!(consumed_note.inputs_hash, note.inputs().commitment());
+        assert_eq!(consumed_note.assets_hash, note.assets().commitment());
+    }
+
+    assert!(executed_transaction.consumed_note(data_store.notes.len() as u32).is_none());
+}
+
 #[test]
 fn executed_transaction_account_delta() {
     let data_store = MockDataStore::new(AssetPreservationStatus::PreservedWithAccountVaultDelta);
@@ -261,6 +410,139 @@ fn executed_transaction_account_delta() {
         removed_assets.len(),
         executed_transaction.account_delta().vault().removed_assets.len()
     );
+
+    // serialization
+    // --------------------------------------------------------------------------------------------
+    // the delta can be transmitted on its own and decoded into the same delta
+    let account_delta = executed_transaction.account_delta();
+    let account_delta_bytes = account_delta.to_bytes();
+    assert_eq!(&AccountDelta::read_from_bytes(&account_delta_bytes).unwrap(), account_delta);
+}
+
+#[test]
+fn executed_transaction_nonce_increment() {
+    let data_store = MockDataStore::default();
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+    let account_procedure_incr_nonce_mast_root =
+        &data_store.account.code().procedures()[ACCOUNT_PROCEDURE_INCR_NONCE_PROC_IDX].to_hex();
+    let account_procedure_set_item_mast_root =
+        &data_store.account.code().procedures()[ACCOUNT_PROCEDURE_SET_ITEM_PROC_IDX].to_hex();
+
+    let mut execute_with_increment = |increment: u64| {
+        // the account state must change for the nonce to be incremented
+        let tx_script = format!(
+            "\
+            begin
+                push.9.8.7.6
+                push.{STORAGE_INDEX_0}
+                push.0 movdn.5 push.0 movdn.5 push.0 movdn.5
+                call.{account_procedure_set_item_mast_root}
+                dropw dropw
+
+                push.{increment}
+                call.{account_procedure_incr_nonce_mast_root}
+                drop
+            end
+            "
+        );
+        let tx_script_code = ProgramAst::parse(&tx_script).unwrap();
+        let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+        let tx_args = TransactionArgs::with_tx_script(tx_script);
+        executor.execute_transaction(account_id, block_ref, &note_ids, Some(tx_args))
+    };
+
+    // a zero increment is rejected by the host
+    assert!(matches!(
+        execute_with_increment(0),
+        Err(TransactionExecutorError::ExecuteTransactionProgramFailed(
+            ExecutionError::EventError(err)
+        )) if err.contains("InvalidNonceIncrement")
+    ));
+
+    // a positive increment is applied to the nonce
+    let executed_transaction = execute_with_increment(2).unwrap();
+    assert_eq!(
+        executed_transaction.account_delta().nonce(),
+        Some(data_store.account.nonce() + Felt::new(2))
+    );
+}
+
+#[test]
+fn executed_transaction_set_storage_note() {
+    // compiling the mock account makes its procedures callable from the note script
+    let assembler = TransactionKernel::assembler();
+    let account = mock_account(None, Felt::new(1), None, &assembler);
+    let account_id = account.id();
+    let updated_slot_value = [Felt::new(9), Felt::new(8), Felt::new(7), Felt::new(6)];
+
+    // the consumed note only sets an item of the account storage
+    let set_item_root = account.code().procedures()[ACCOUNT_PROCEDURE_SET_ITEM_PROC_IDX];
+    let note_script_ast =
+        set_storage_note_script(set_item_root, STORAGE_INDEX_0, updated_slot_value);
+    let (note_script, _) = NoteScript::new(note_script_ast, &assembler).unwrap();
+    let note_asset: Asset = FungibleAsset::new(
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN.try_into().unwrap(),
+        FUNGIBLE_ASSET_AMOUNT,
+    )
+    .unwrap()
+    .into();
+    let serial_num = [Felt::new(31), Felt::new(32), Felt::new(33), Felt::new(34)];
+    let note = Note::new(note_script, &[], &[note_asset], serial_num, account_id, ZERO).unwrap();
+
+    let (account, block_header, block_chain, notes, _) = mock_inputs_with_existing(
+        MockAccountType::StandardExisting,
+        AssetPreservationStatus::Preserved,
+        Some(account),
+        Some(vec![note]),
+    );
+    let data_store = MockDataStore {
+        account,
+        block_header,
+        block_chain,
+        notes,
+    };
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account_id).unwrap();
+
+    // the transaction script keeps the note asset and increments the nonce of the account
+    let account_procedure_incr_nonce_mast_root =
+        &data_store.account.code().procedures()[ACCOUNT_PROCEDURE_INCR_NONCE_PROC_IDX].to_hex();
+    let tx_script = format!(
+        "\
+        use.miden::contracts::wallets::basic->wallet
+
+        begin
+            push.{note_asset}
+            call.wallet::receive_asset
+            dropw
+
+            push.1
+            call.{account_procedure_incr_nonce_mast_root}
+            drop
+        end
+        ",
+        note_asset = prepare_word(&note_asset.into())
+    );
+    let tx_script_code = ProgramAst::parse(&tx_script).unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+    let executed_transaction = executor
+        .execute_transaction(account_id, block_ref, &note_ids, Some(tx_args))
+        .unwrap();
+
+    assert_eq!(
+        executed_transaction.account_delta().storage().updated_items,
+        vec![(STORAGE_INDEX_0, updated_slot_value)]
+    );
 }
 
 #[test]
@@ -292,6 +574,36 @@ fn prove_witness_and_verify() {
     assert!(verifier.verify(proven_transaction).is_ok());
 }
 
+#[test]
+fn prove_witness_and_verify_with_security_level() {
+    let data_store = MockDataStore::default();
+    let mut executor = TransactionExecutor::new(data_store.clone());
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &note_ids, None).unwrap();
+
+    // a proof generated with 128-bit security options verifies under the matching security level
+    let prover = TransactionProver::new(ProvingOptions::with_128_bit_security(false));
+    let proven_transaction = prover.prove_transaction(executed_transaction.clone()).unwrap();
+    let verifier = TransactionVerifier::new(128);
+    assert!(verifier.verify(proven_transaction).is_ok());
+
+    // a proof generated with the default (96-bit) options is rejected if 128-bit security is
+    // required
+    let prover = TransactionProver::new(ProvingOptions::default());
+    let proven_transaction = prover.prove_transaction(executed_transaction).unwrap();
+    assert!(matches!(
+        verifier.verify(proven_transaction),
+        Err(TransactionVerifierError::InsufficientProofSecurityLevel(..))
+    ));
+}
+
 // TEST TRANSACTION SCRIPT
 // ================================================================================================
 
@@ -342,6 +654,289 @@ fn test_tx_script() {
     assert!(executed_transaction.is_ok());
 }
 
+#[test]
+fn test_tx_script_cache() {
+    let data_store = MockDataStore::default();
+    let mut executor = TransactionExecutor::new(data_store.clone());
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let account_procedure_incr_nonce_mast_root =
+        &data_store.account.code().procedures()[ACCOUNT_PROCEDURE_INCR_NONCE_PROC_IDX].to_hex();
+    let tx_script_source = format!(
+        "
+    begin
+        push.1
+        call.{account_procedure_incr_nonce_mast_root}
+        drop
+    end
+"
+    );
+    let tx_script_code = ProgramAst::parse(&tx_script_source).unwrap();
+    let tx_script_input_key = [Felt::new(9999), Felt::new(8888), Felt::new(9999), Felt::new(8888)];
+    let tx_script_input_value = vec![Felt::new(9), Felt::new(8), Felt::new(7), Felt::new(6)];
+
+    // identical sources result in the same compiled script, regardless of the script inputs
+    let tx_script = executor.compile_tx_script(tx_script_code.clone(), vec![], vec![]).unwrap();
+    let cached_tx_script = executor
+        .compile_tx_script(
+            tx_script_code.clone(),
+            vec![(tx_script_input_key, tx_script_input_value)],
+            vec![],
+        )
+        .unwrap();
+    assert_eq!(cached_tx_script.hash(), tx_script.hash());
+    assert_eq!(cached_tx_script.code(), tx_script.code());
+    assert_eq!(cached_tx_script.inputs().len(), 1);
+
+    // cached scripts are still checked against the target account interfaces
+    let unrelated_interface = vec![data_store.account.code().procedures()[0]];
+    assert!(executor
+        .compile_tx_script(
+            tx_script_code.clone(),
+            vec![],
+            vec![ScriptTarget::Procedures(unrelated_interface)]
+        )
+        .is_err());
+
+    // the script is compiled again after the cache is cleared
+    executor.clear_script_cache();
+    let recompiled_tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    assert_eq!(recompiled_tx_script.hash(), tx_script.hash());
+}
+
+#[test]
+fn test_tx_script_args() {
+    let data_store = MockDataStore::default();
+    let mut executor = TransactionExecutor::new(data_store.clone());
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let arg_0 = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    let arg_1 = [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)];
+    let arg_2 = [Felt::new(9), Felt::new(10), Felt::new(11), Felt::new(12)];
+    let tx_script_args = TxScriptArgs::new().with_arg(arg_0).with_arg(arg_1).with_arg(arg_2);
+
+    let tx_script_source = format!(
+        "
+    begin
+        # => [ARG_0, ARG_1, ARG_2]
+
+        # assert that the arguments are laid out as documented
+        push.{arg_0} assert_eqw
+        push.{arg_1} assert_eqw
+        push.{arg_2} assert_eqw
+    end
+",
+        arg_0 = prepare_word(&arg_0),
+        arg_1 = prepare_word(&arg_1),
+        arg_2 = prepare_word(&arg_2),
+    );
+    let tx_script_code = ProgramAst::parse(&tx_script_source).unwrap();
+    let tx_script = executor
+        .compile_tx_script_with_args(tx_script_code, tx_script_args, vec![], vec![])
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    // execute the transaction
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &note_ids, Some(tx_args));
+
+    // assert the transaction executed successfully
+    assert!(executed_transaction.is_ok());
+}
+
+#[test]
+fn input_note_with_and_without_proof() {
+    let data_store = MockDataStore::default();
+    let note = data_store.notes[0].note().clone();
+    let proof = data_store.notes[0].proof().unwrap().clone();
+
+    let proven_note = InputNote::new(note.clone(), proof.clone());
+    assert!(proven_note.has_proof());
+    assert_eq!(proven_note.proof(), Some(&proof));
+    assert_eq!(proven_note.origin(), Some(proof.origin()));
+
+    let local_note = InputNote::without_proof(note.clone());
+    assert!(!local_note.has_proof());
+    assert!(local_note.proof().is_none());
+    assert!(local_note.origin().is_none());
+    assert_eq!(local_note.note(), &note);
+
+    for input_note in [&proven_note, &local_note] {
+        let bytes = input_note.to_bytes();
+        assert_eq!(&InputNote::read_from_bytes(&bytes).unwrap(), input_note);
+    }
+
+    // notes without inclusion proofs cannot be consumed
+    let result = TransactionInputs::new(
+        data_store.account.clone(),
+        None,
+        data_store.block_header,
+        data_store.block_chain.clone(),
+        InputNotes::new(vec![local_note]).unwrap(),
+    );
+    assert_eq!(result, Err(TransactionInputError::InputNoteMissingProof(note.id())));
+}
+
+#[test]
+fn data_store_get_note_by_nullifier() {
+    let data_store = MockDataStore::default();
+
+    let input_note = &data_store.notes[1];
+    let nullifier = input_note.note().nullifier();
+    assert_eq!(data_store.get_note_by_nullifier(nullifier).unwrap().as_ref(), Some(input_note));
+
+    let unknown_nullifier = Nullifier::from(Digest::default());
+    assert!(data_store.get_note_by_nullifier(unknown_nullifier).unwrap().is_none());
+}
+
+#[test]
+fn check_asset_preservation_statuses() {
+    let assembler = TransactionKernel::assembler();
+    // compiling the mock account makes its procedures callable from the mock note scripts
+    let vault = mock_account(None, Felt::new(1), None, &assembler).vault().clone();
+    let empty_vault = AssetVault::new(&[]).unwrap();
+
+    // created notes carry assets which were not consumed
+    let (consumed, created) = mock_notes(&assembler, &AssetPreservationStatus::TooFewInput);
+    assert!(matches!(
+        check_asset_preservation(&empty_vault, &consumed, &created),
+        Err(AssetError::AssetAmountNotSufficient(0, _))
+    ));
+
+    // the account vault does not hold the missing asset of the third faucet either
+    assert!(check_asset_preservation(&vault, &consumed, &created).is_err());
+
+    // consumed and created assets balance
+    let (consumed, created) = mock_notes(&assembler, &AssetPreservationStatus::Preserved);
+    assert!(check_asset_preservation(&empty_vault, &consumed, &created).is_ok());
+
+    // surplus consumed assets are moved into the account vault
+    let (consumed, created) =
+        mock_notes(&assembler, &AssetPreservationStatus::TooManyFungibleInput);
+    assert!(check_asset_preservation(&empty_vault, &consumed, &created).is_ok());
+
+    let (consumed, created) =
+        mock_notes(&assembler, &AssetPreservationStatus::TooManyNonFungibleInput);
+    assert!(check_asset_preservation(&empty_vault, &consumed, &created).is_ok());
+
+    // a non-fungible asset which is neither consumed nor in the vault cannot be sent
+    let (consumed, mut created) = mock_notes(&assembler, &AssetPreservationStatus::Preserved);
+    created.push(mock_note_with_assets(
+        consumed[0].metadata().sender(),
+        &[non_fungible_asset(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN)],
+        consumed[0].script().clone(),
+        [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)],
+    ));
+    assert!(matches!(
+        check_asset_preservation(&empty_vault, &consumed, &created),
+        Err(AssetError::NonFungibleAssetNotAvailable(_))
+    ));
+}
+
+#[test]
+fn faucet_ids_of_mock_vault_and_note() {
+    let assembler = TransactionKernel::assembler();
+    let vault = mock_account(None, Felt::new(1), None, &assembler).vault().clone();
+
+    let expected: BTreeSet<AccountId> = [
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1,
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
+        ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+    ]
+    .into_iter()
+    .map(|id| AccountId::try_from(id).unwrap())
+    .collect();
+    assert_eq!(vault.faucet_ids(), expected);
+
+    // assets issued by the same faucet are reported once
+    let (script, _) =
+        NoteScript::new(ProgramAst::parse("begin push.1 drop end").unwrap(), &assembler).unwrap();
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let serial_num = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    let assets = [
+        non_fungible_asset(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN),
+        non_fungible_asset_2(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN),
+    ];
+    let note = mock_note_with_assets(sender, &assets, script, serial_num);
+    assert_eq!(
+        note.faucet_ids(),
+        BTreeSet::from([AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap()])
+    );
+}
+
+#[test]
+fn mock_note_with_caller_specified_assets() {
+    let assembler = TransactionKernel::assembler();
+    let (script, _) =
+        NoteScript::new(ProgramAst::parse("begin push.1 drop end").unwrap(), &assembler).unwrap();
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let serial_num = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    let nft = non_fungible_asset(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN);
+
+    let note = mock_note_with_assets(sender, &[nft], script.clone(), serial_num);
+    assert_eq!(note.assets().iter().collect::<Vec<_>>(), vec![&nft]);
+    assert_eq!(note.metadata().sender(), sender);
+    assert_eq!(note.serial_num(), serial_num);
+    assert_eq!(note.script(), &script);
+    assert!(note.inputs().values().is_empty());
+}
+
+#[test]
+fn aggregate_created_note_assets() {
+    let assembler = TransactionKernel::assembler();
+    // compiling the mock account makes its procedures callable from the mock note scripts
+    mock_account(None, Felt::new(1), None, &assembler);
+    let (consumed, created) = mock_notes(&assembler, &AssetPreservationStatus::Preserved);
+
+    // the created notes carry fungible assets of three distinct faucets
+    let mut expected = created
+        .iter()
+        .flat_map(|note| note.assets().iter().copied())
+        .collect::<Vec<_>>();
+    expected.sort_by_key(|asset| asset.faucet_id());
+    assert_eq!(aggregate_assets(&created).unwrap(), expected);
+
+    // fungible assets of the same faucet are summed across notes; the consumed notes carry the
+    // same assets as the created notes
+    let all_notes = consumed.iter().chain(created.iter()).cloned().collect::<Vec<_>>();
+    let doubled = expected
+        .iter()
+        .map(|asset| {
+            let asset = asset.as_fungible().unwrap();
+            FungibleAsset::new(asset.faucet_id(), asset.amount() * 2).unwrap().into()
+        })
+        .collect::<Vec<Asset>>();
+    assert_eq!(aggregate_assets(&all_notes).unwrap(), doubled);
+}
+
+#[test]
+fn split_note_by_asset() {
+    let assembler = TransactionKernel::assembler();
+    // compiling the mock account makes its procedures callable from the mock note scripts
+    mock_account(None, Felt::new(1), None, &assembler);
+    let (consumed, _) = mock_notes(&assembler, &AssetPreservationStatus::Preserved);
+    let note = &consumed[1];
+    assert_eq!(note.assets().num_assets(), 2);
+
+    let split_notes = note.split_by_asset();
+    assert_eq!(split_notes.len(), 2);
+    for (split_note, asset) in split_notes.iter().zip(note.assets().iter()) {
+        assert_eq!(split_note.assets().iter().collect::<Vec<_>>(), vec![asset]);
+        assert_eq!(split_note.script(), note.script());
+        assert_eq!(split_note.metadata(), note.metadata());
+        assert_ne!(split_note.serial_num(), note.serial_num());
+    }
+    assert_ne!(split_notes[0].serial_num(), split_notes[1].serial_num());
+}
+
 // MOCK DATA STORE
 // ================================================================================================
 
@@ -405,4 +1000,11 @@ impl DataStore for MockDataStore {
         assert_eq!(account_id, self.account.id());
         Ok(self.account.code().module().clone())
     }
+
+    fn get_note_by_nullifier(
+        &self,
+        nullifier: Nullifier,
+    ) -> Result<Option<InputNote>, DataStoreError> {
+        Ok(self.notes.iter().find(|note| note.note().nullifier() == nullifier).cloned())
+    }
 }
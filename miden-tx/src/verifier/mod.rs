@@ -1,5 +1,10 @@
 use miden_lib::transaction::TransactionKernel;
-use miden_objects::{transaction::ProvenTransaction, vm::ProgramInfo};
+use miden_objects::{
+    accounts::Account,
+    transaction::{InputNotes, OutputNotes, ProvenTransaction},
+    vm::{ProgramInfo, StackInputs, StackOutputs},
+    Digest,
+};
 use miden_verifier::verify;
 
 use super::TransactionVerifierError;
@@ -32,23 +37,13 @@ impl TransactionVerifier {
     /// - The security level of the verified proof is insufficient.
     pub fn verify(&self, transaction: ProvenTransaction) -> Result<(), TransactionVerifierError> {
         // build stack inputs and outputs
-        let stack_inputs = TransactionKernel::build_input_stack(
-            transaction.account_id(),
-            transaction.initial_account_hash(),
-            transaction.input_notes().commitment(),
-            transaction.block_ref(),
-        );
-        let stack_outputs = TransactionKernel::build_output_stack(
-            transaction.final_account_hash(),
-            transaction.output_notes().commitment(),
-            transaction.tx_script_root(),
-        );
+        let public_inputs = TransactionPublicInputs::from(&transaction);
 
         // verify transaction proof
         let proof_security_level = verify(
             self.tx_program_info.clone(),
-            stack_inputs,
-            stack_outputs,
+            public_inputs.stack_inputs,
+            public_inputs.stack_outputs,
             transaction.proof().clone(),
         )
         .map_err(TransactionVerifierError::TransactionVerificationFailed)?;
@@ -64,3 +59,76 @@ impl TransactionVerifier {
         Ok(())
     }
 }
+
+// TRANSACTION PUBLIC INPUTS
+// ================================================================================================
+
+/// Public inputs against which a transaction proof is verified.
+///
+/// These consist of the stack inputs and the stack outputs of the transaction kernel program, and
+/// can be assembled without executing the transaction as long as the final account hash and the
+/// output notes of the transaction are known.
+#[derive(Debug, Clone)]
+pub struct TransactionPublicInputs {
+    stack_inputs: StackInputs,
+    stack_outputs: StackOutputs,
+}
+
+impl TransactionPublicInputs {
+    /// Returns the stack inputs of the transaction kernel program.
+    pub fn stack_inputs(&self) -> &StackInputs {
+        &self.stack_inputs
+    }
+
+    /// Returns the stack outputs of the transaction kernel program.
+    pub fn stack_outputs(&self) -> &StackOutputs {
+        &self.stack_outputs
+    }
+}
+
+impl From<&ProvenTransaction> for TransactionPublicInputs {
+    fn from(transaction: &ProvenTransaction) -> Self {
+        let stack_inputs = TransactionKernel::build_input_stack(
+            transaction.account_id(),
+            transaction.initial_account_hash(),
+            transaction.input_notes().commitment(),
+            transaction.block_ref(),
+        );
+        let stack_outputs = TransactionKernel::build_output_stack(
+            transaction.final_account_hash(),
+            transaction.output_notes().commitment(),
+            transaction.tx_script_root(),
+        );
+
+        Self { stack_inputs, stack_outputs }
+    }
+}
+
+/// Returns the public inputs of a transaction executed against the specified initial account state.
+///
+/// The final account hash and the output notes are the values claimed by the party which executed
+/// the transaction; a proof verified against the returned inputs attests to these values. The
+/// block hash is the hash of the block referenced by the transaction and the transaction script
+/// root is the root of the script executed as a part of the transaction, if any.
+pub fn transaction_public_inputs(
+    initial_account: &Account,
+    final_account_hash: Digest,
+    block_hash: Digest,
+    input_notes: &InputNotes,
+    output_notes: &OutputNotes,
+    tx_script_root: Option<Digest>,
+) -> TransactionPublicInputs {
+    let stack_inputs = TransactionKernel::build_input_stack(
+        initial_account.id(),
+        initial_account.proof_init_hash(),
+        input_notes.commitment(),
+        block_hash,
+    );
+    let stack_outputs = TransactionKernel::build_output_stack(
+        final_account_hash,
+        output_notes.commitment(),
+        tx_script_root,
+    );
+
+    TransactionPublicInputs { stack_inputs, stack_outputs }
+}
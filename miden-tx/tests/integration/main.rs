@@ -3,7 +3,7 @@ mod wallet;
 
 use miden_lib::transaction::TransactionKernel;
 use miden_objects::{
-    accounts::{Account, AccountCode, AccountId, AccountStorage, StorageSlotType},
+    accounts::{Account, AccountId, AccountStorage, StorageSlotType},
     assembly::{ModuleAst, ProgramAst},
     assets::{Asset, AssetVault, FungibleAsset},
     crypto::{dsa::rpo_falcon512::KeyPair, utils::Serializable},
@@ -148,11 +148,6 @@ pub fn get_account_with_default_account_code(
     public_key: Word,
     assets: Option<Asset>,
 ) -> Account {
-    let account_code_src = DEFAULT_ACCOUNT_CODE;
-    let account_code_ast = ModuleAst::parse(account_code_src).unwrap();
-    let account_assembler = TransactionKernel::assembler();
-
-    let account_code = AccountCode::new(account_code_ast.clone(), &account_assembler).unwrap();
     let account_storage =
         AccountStorage::new(vec![(0, (StorageSlotType::Value { value_arity: 0 }, public_key))])
             .unwrap();
@@ -162,7 +157,15 @@ pub fn get_account_with_default_account_code(
         None => AssetVault::new(&[]).unwrap(),
     };
 
-    Account::new(account_id, account_vault, account_storage, account_code, Felt::new(1))
+    Account::from_code_source(
+        account_id,
+        DEFAULT_ACCOUNT_CODE,
+        &TransactionKernel::assembler(),
+        account_storage,
+        account_vault,
+        Felt::new(1),
+    )
+    .unwrap()
 }
 
 #[allow(dead_code)]
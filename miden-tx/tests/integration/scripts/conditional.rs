@@ -0,0 +1,122 @@
+use miden_lib::notes::utils::conditional_note_script;
+use miden_objects::{
+    accounts::{Account, AccountId},
+    assembly::ProgramAst,
+    assets::{AssetVault, FungibleAsset},
+    transaction::TransactionArgs,
+    utils::collections::*,
+    Felt,
+};
+use miden_tx::TransactionExecutor;
+use mock::constants::{
+    ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
+    DEFAULT_AUTH_SCRIPT,
+};
+
+use crate::{
+    get_account_with_default_account_code, get_new_key_pair_with_advice_map,
+    get_note_with_fungible_asset_and_script, MockDataStore,
+};
+
+// CONDITIONAL TESTS
+// ===============================================================================================
+// A conditional note can only be consumed by an account whose storage slot holds the expected
+// value. The note wraps a script which adds the note assets to the vault of the consuming account.
+#[test]
+fn conditional_script() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset = FungibleAsset::new(faucet_id, 100).unwrap();
+
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let (target_pub_key, target_keypair_felt) = get_new_key_pair_with_advice_map();
+    let target_account =
+        get_account_with_default_account_code(target_account_id, target_pub_key, None);
+
+    let inner_script = ProgramAst::parse(
+        "
+    use.miden::note
+    use.miden::contracts::wallets::basic->wallet
+
+    begin
+        dropw
+        exec.note::get_assets drop
+        mem_loadw
+        call.wallet::receive_asset
+        dropw
+    end
+    ",
+    )
+    .unwrap();
+
+    // Create the notes (Note: storage slot 0 of the target account holds its public key)
+    let other_value = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    let note_mismatch = get_note_with_fungible_asset_and_script(
+        fungible_asset,
+        conditional_note_script(0, other_value, inner_script.clone()),
+    );
+    let note_match = get_note_with_fungible_asset_and_script(
+        fungible_asset,
+        conditional_note_script(0, target_pub_key, inner_script),
+    );
+
+    let tx_script_code = ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap();
+
+    // CONSTRUCT AND EXECUTE TX (Case "mismatch" - Execution Failure)
+    // --------------------------------------------------------------------------------------------
+    let data_store_1 =
+        MockDataStore::with_existing(Some(target_account.clone()), Some(vec![note_mismatch]));
+    let mut executor_1 = TransactionExecutor::new(data_store_1.clone());
+    executor_1.load_account(target_account_id).unwrap();
+
+    let block_ref_1 = data_store_1.block_header.block_num();
+    let note_ids_1 = data_store_1.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script_1 = executor_1
+        .compile_tx_script(
+            tx_script_code.clone(),
+            vec![(target_pub_key, target_keypair_felt.clone())],
+            vec![],
+        )
+        .unwrap();
+    let tx_args_1 = TransactionArgs::with_tx_script(tx_script_1);
+
+    let executed_transaction_1 = executor_1.execute_transaction(
+        target_account_id,
+        block_ref_1,
+        &note_ids_1,
+        Some(tx_args_1),
+    );
+
+    // The storage slot does not hold the expected value, we expect an error
+    assert!(executed_transaction_1.is_err());
+
+    // CONSTRUCT AND EXECUTE TX (Case "match" - Execution Success)
+    // --------------------------------------------------------------------------------------------
+    let data_store_2 =
+        MockDataStore::with_existing(Some(target_account.clone()), Some(vec![note_match]));
+    let mut executor_2 = TransactionExecutor::new(data_store_2.clone());
+    executor_2.load_account(target_account_id).unwrap();
+
+    let block_ref_2 = data_store_2.block_header.block_num();
+    let note_ids_2 = data_store_2.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script_2 = executor_2
+        .compile_tx_script(tx_script_code, vec![(target_pub_key, target_keypair_felt)], vec![])
+        .unwrap();
+    let tx_args_2 = TransactionArgs::with_tx_script(tx_script_2);
+
+    let executed_transaction_2 = executor_2
+        .execute_transaction(target_account_id, block_ref_2, &note_ids_2, Some(tx_args_2))
+        .unwrap();
+
+    // Assert that the target account received the funds and the nonce increased by 1
+    let target_account_after = Account::new(
+        target_account_id,
+        AssetVault::new(&[fungible_asset.into()]).unwrap(),
+        target_account.storage().clone(),
+        target_account.code().clone(),
+        Felt::new(2),
+    );
+    assert_eq!(executed_transaction_2.final_account().hash(), target_account_after.hash());
+}
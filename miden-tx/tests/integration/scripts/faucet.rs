@@ -86,7 +86,7 @@ fn prove_faucet_contract_mint_fungible_asset_succeeds() {
     let expected_note = OutputNote::new(
         recipient.into(),
         NoteAssets::new(&[fungible_asset]).unwrap(),
-        NoteMetadata::new(faucet_account.id(), tag),
+        NoteMetadata::new(faucet_account.id(), tag.try_into().unwrap()),
     );
 
     let created_note = executed_transaction.output_notes().get_note(0).clone();
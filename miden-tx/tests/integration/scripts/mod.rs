@@ -1,4 +1,8 @@
+mod conditional;
 mod faucet;
 mod p2id;
 mod p2idr;
+mod partial_claim;
+mod signed;
 mod swap;
+mod timelock;
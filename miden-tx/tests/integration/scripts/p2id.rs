@@ -1,9 +1,10 @@
-use miden_lib::notes::create_p2id_note;
+use miden_lib::notes::{create_p2id_note, create_p2id_note_with_serial_num};
 use miden_objects::{
     accounts::{Account, AccountId},
     assembly::ProgramAst,
     assets::{Asset, AssetVault, FungibleAsset},
     crypto::rand::RpoRandomCoin,
+    notes::{NoteTag, NoteType},
     transaction::TransactionArgs,
     utils::collections::*,
     Felt,
@@ -237,3 +238,97 @@ fn p2id_script_multiple_assets() {
     // Check that we got the expected result - TransactionExecutorError
     assert!(executed_transaction_2.is_err());
 }
+
+/// We test a P2ID note built with a caller-provided serial number. The note can be consumed by the
+/// target account but not by any other account.
+#[test]
+fn p2id_script_with_serial_num() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let (target_pub_key, target_sk_pk_felt) = get_new_key_pair_with_advice_map();
+    let target_account =
+        get_account_with_default_account_code(target_account_id, target_pub_key, None);
+
+    let serial_num = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    let note = create_p2id_note_with_serial_num(
+        sender_account_id,
+        target_account_id,
+        &[fungible_asset],
+        NoteType::OffChain,
+        serial_num,
+    )
+    .unwrap();
+    assert_eq!(note.serial_num(), serial_num);
+    assert_eq!(
+        note.metadata().tag(),
+        NoteTag::for_account(target_account_id, NoteType::OffChain).unwrap()
+    );
+
+    let tx_script_code = ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap();
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let data_store =
+        MockDataStore::with_existing(Some(target_account.clone()), Some(vec![note.clone()]));
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(target_account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+    let tx_script_target = executor
+        .compile_tx_script(
+            tx_script_code.clone(),
+            vec![(target_pub_key, target_sk_pk_felt)],
+            vec![],
+        )
+        .unwrap();
+    let tx_args_target = TransactionArgs::with_tx_script(tx_script_target);
+
+    let executed_transaction = executor
+        .execute_transaction(target_account_id, block_ref, &note_ids, Some(tx_args_target))
+        .unwrap();
+
+    let target_account_after: Account = Account::new(
+        target_account.id(),
+        AssetVault::new(&[fungible_asset]).unwrap(),
+        target_account.storage().clone(),
+        target_account.code().clone(),
+        Felt::new(2),
+    );
+    assert_eq!(executed_transaction.final_account().hash(), target_account_after.hash());
+
+    // CONSTRUCT AND EXECUTE TX (Failure)
+    // --------------------------------------------------------------------------------------------
+    let malicious_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN + 1).unwrap();
+    let (malicious_pub_key, malicious_keypair_felt) = get_new_key_pair_with_advice_map();
+    let malicious_account =
+        get_account_with_default_account_code(malicious_account_id, malicious_pub_key, None);
+
+    let data_store = MockDataStore::with_existing(Some(malicious_account), Some(vec![note]));
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(malicious_account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+    let tx_script_malicious = executor
+        .compile_tx_script(
+            tx_script_code,
+            vec![(malicious_pub_key, malicious_keypair_felt)],
+            vec![],
+        )
+        .unwrap();
+    let tx_args_malicious = TransactionArgs::with_tx_script(tx_script_malicious);
+
+    let executed_transaction = executor.execute_transaction(
+        malicious_account_id,
+        block_ref,
+        &note_ids,
+        Some(tx_args_malicious),
+    );
+    assert!(executed_transaction.is_err());
+}
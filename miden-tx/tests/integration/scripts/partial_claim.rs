@@ -0,0 +1,84 @@
+use miden_lib::notes::{create_partial_claim_note, utils::build_p2id_recipient};
+use miden_objects::{
+    accounts::{Account, AccountId, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN},
+    assembly::ProgramAst,
+    assets::{AssetVault, FungibleAsset},
+    crypto::rand::RpoRandomCoin,
+    notes::{NoteAssets, NoteMetadata},
+    transaction::{OutputNote, TransactionArgs},
+    Felt,
+};
+use miden_tx::TransactionExecutor;
+use mock::constants::{
+    ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN, ACCOUNT_ID_SENDER, DEFAULT_AUTH_SCRIPT,
+};
+
+use crate::{
+    get_account_with_default_account_code, get_new_key_pair_with_advice_map, MockDataStore,
+};
+
+#[test]
+fn partial_claim_script() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset = FungibleAsset::new(faucet_id, 100).unwrap();
+
+    // Create sender and target account
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let (target_pub_key, target_sk_felt) = get_new_key_pair_with_advice_map();
+    let target_account =
+        get_account_with_default_account_code(target_account_id, target_pub_key, None);
+
+    // Create the note claiming 60 out of 100 units
+    let (note, change_serial_num) = create_partial_claim_note(
+        sender_account_id,
+        fungible_asset,
+        60,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let data_store =
+        MockDataStore::with_existing(Some(target_account.clone()), Some(vec![note.clone()]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(target_account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script_code = ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap();
+    let tx_script_target = executor
+        .compile_tx_script(tx_script_code, vec![(target_pub_key, target_sk_felt)], vec![])
+        .unwrap();
+    let tx_args_target = TransactionArgs::new(Some(tx_script_target), None);
+
+    let executed_transaction = executor
+        .execute_transaction(target_account_id, block_ref, &note_ids, Some(tx_args_target))
+        .unwrap();
+
+    // Check that the target account has received the claimed amount
+    let target_account_after: Account = Account::new(
+        target_account.id(),
+        AssetVault::new(&[FungibleAsset::new(faucet_id, 60).unwrap().into()]).unwrap(),
+        target_account.storage().clone(),
+        target_account.code().clone(),
+        Felt::new(2),
+    );
+    assert_eq!(executed_transaction.final_account().hash(), target_account_after.hash());
+
+    // Check that a change note carrying the remaining 40 units back to the sender was created
+    assert_eq!(executed_transaction.output_notes().num_notes(), 1);
+
+    let recipient = build_p2id_recipient(sender_account_id, change_serial_num).unwrap();
+    let note_metadata = NoteMetadata::new(target_account_id, sender_account_id.try_into().unwrap());
+    let note_assets =
+        NoteAssets::new(&[FungibleAsset::new(faucet_id, 40).unwrap().into()]).unwrap();
+    let change_note = OutputNote::new(recipient, note_assets, note_metadata);
+
+    assert_eq!(executed_transaction.output_notes().get_note(0), &change_note);
+}
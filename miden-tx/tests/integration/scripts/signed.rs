@@ -0,0 +1,151 @@
+use miden_objects::{
+    accounts::{Account, AccountId},
+    assembly::ProgramAst,
+    assets::{AssetVault, FungibleAsset},
+    transaction::TransactionArgs,
+    utils::collections::*,
+    Felt, Word,
+};
+use miden_tx::TransactionExecutor;
+use mock::constants::{
+    ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
+    DEFAULT_AUTH_SCRIPT,
+};
+
+use crate::{
+    get_account_with_default_account_code, get_new_key_pair_with_advice_map,
+    get_note_with_fungible_asset_and_script, MockDataStore,
+};
+
+// SIGNED NOTE TESTS
+// ===============================================================================================
+// A signed note can only be consumed if the signature of the trusted sender over the note can be
+// verified. The note adds its assets to the vault of the consuming account.
+#[test]
+fn signed_note_script() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset = FungibleAsset::new(faucet_id, 100).unwrap();
+
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let (target_pub_key, target_keypair_felt) = get_new_key_pair_with_advice_map();
+    let target_account =
+        get_account_with_default_account_code(target_account_id, target_pub_key, None);
+
+    let (sender_pub_key, sender_keypair_felt) = get_new_key_pair_with_advice_map();
+    let (other_pub_key, other_keypair_felt) = get_new_key_pair_with_advice_map();
+
+    let note_script = |expected_message: Word| {
+        let expected_message = expected_message
+            .iter()
+            .map(|felt| format!("{}", felt.as_int()))
+            .collect::<Vec<_>>()
+            .join(".");
+        let sender_pub_key = sender_pub_key
+            .iter()
+            .map(|felt| format!("{}", felt.as_int()))
+            .collect::<Vec<_>>()
+            .join(".");
+        ProgramAst::parse(&format!(
+            "
+        use.miden::note
+        use.miden::contracts::wallets::basic->wallet
+
+        begin
+            dropw
+
+            # the message computed by the note script matches the one signed off-chain
+            exec.note::get_signature_message push.{expected_message} assert_eqw
+
+            # the note was signed by the trusted sender
+            push.{sender_pub_key} exec.note::verify_signature
+
+            exec.note::get_assets drop
+            mem_loadw
+            call.wallet::receive_asset
+            dropw
+        end
+        "
+        ))
+        .unwrap()
+    };
+
+    // the signature message does not commit to the note script, so it can be computed from a note
+    // with a placeholder script
+    let placeholder = get_note_with_fungible_asset_and_script(
+        fungible_asset,
+        ProgramAst::parse("begin dropw end").unwrap(),
+    );
+    let note = get_note_with_fungible_asset_and_script(
+        fungible_asset,
+        note_script(placeholder.signature_message()),
+    );
+    assert_eq!(note.signature_message(), placeholder.signature_message());
+
+    let tx_script_code = ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap();
+
+    // CONSTRUCT AND EXECUTE TX (Case "other signer" - Execution Failure)
+    // --------------------------------------------------------------------------------------------
+    let data_store_1 =
+        MockDataStore::with_existing(Some(target_account.clone()), Some(vec![note.clone()]));
+    let mut executor_1 = TransactionExecutor::new(data_store_1.clone());
+    executor_1.load_account(target_account_id).unwrap();
+
+    let block_ref_1 = data_store_1.block_header.block_num();
+    let note_ids_1 = data_store_1.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    // only a signature by a different key can be provided
+    let tx_script_1 = executor_1
+        .compile_tx_script(
+            tx_script_code.clone(),
+            vec![
+                (target_pub_key, target_keypair_felt.clone()),
+                (other_pub_key, other_keypair_felt),
+            ],
+            vec![],
+        )
+        .unwrap();
+    let tx_args_1 = TransactionArgs::with_tx_script(tx_script_1);
+
+    let executed_transaction_1 = executor_1.execute_transaction(
+        target_account_id,
+        block_ref_1,
+        &note_ids_1,
+        Some(tx_args_1),
+    );
+
+    // The note was not signed by the trusted sender, we expect an error
+    assert!(executed_transaction_1.is_err());
+
+    // CONSTRUCT AND EXECUTE TX (Case "trusted signer" - Execution Success)
+    // --------------------------------------------------------------------------------------------
+    let data_store_2 = MockDataStore::with_existing(Some(target_account.clone()), Some(vec![note]));
+    let mut executor_2 = TransactionExecutor::new(data_store_2.clone());
+    executor_2.load_account(target_account_id).unwrap();
+
+    let block_ref_2 = data_store_2.block_header.block_num();
+    let note_ids_2 = data_store_2.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script_2 = executor_2
+        .compile_tx_script(
+            tx_script_code,
+            vec![(target_pub_key, target_keypair_felt), (sender_pub_key, sender_keypair_felt)],
+            vec![],
+        )
+        .unwrap();
+    let tx_args_2 = TransactionArgs::with_tx_script(tx_script_2);
+
+    let executed_transaction_2 = executor_2
+        .execute_transaction(target_account_id, block_ref_2, &note_ids_2, Some(tx_args_2))
+        .unwrap();
+
+    // Assert that the target account received the funds and the nonce increased by 1
+    let target_account_after = Account::new(
+        target_account_id,
+        AssetVault::new(&[fungible_asset.into()]).unwrap(),
+        target_account.storage().clone(),
+        target_account.code().clone(),
+        Felt::new(2),
+    );
+    assert_eq!(executed_transaction_2.final_account().hash(), target_account_after.hash());
+}
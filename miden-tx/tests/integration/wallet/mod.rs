@@ -1,13 +1,27 @@
-use miden_lib::{accounts::wallets::create_basic_wallet, AuthScheme};
+use miden_lib::{
+    accounts::{
+        create_and_fund_account,
+        faucets::create_basic_fungible_faucet,
+        wallets::{create_basic_wallet, create_basic_wallet_with_auth_schemes},
+    },
+    notes::create_p2id_note_with_serial_num,
+    rotate_auth_key_script,
+    transaction::TransactionKernel,
+    AuthScheme,
+};
 use miden_objects::{
     accounts::{Account, AccountId, AccountStorage, StorageSlotType},
     assembly::ProgramAst,
-    assets::{Asset, AssetVault, FungibleAsset},
-    crypto::dsa::rpo_falcon512::{KeyPair, PublicKey},
+    assets::{Asset, AssetVault, FungibleAsset, TokenSymbol},
+    crypto::{
+        dsa::rpo_falcon512::{KeyPair, PublicKey},
+        utils::Serializable,
+    },
+    notes::NoteType,
     transaction::TransactionArgs,
     Felt, Word, ONE, ZERO,
 };
-use miden_tx::TransactionExecutor;
+use miden_tx::{Intent, TransactionBuilder, TransactionCompiler, TransactionExecutor};
 use mock::{
     constants::{
         ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
@@ -160,6 +174,8 @@ fn prove_send_asset_via_wallet() {
     let executed_transaction = executor
         .execute_transaction(sender_account.id(), block_ref, &note_ids, Some(tx_args))
         .unwrap();
+    assert!(executed_transaction.nonce_incremented());
+    assert_eq!(executed_transaction.nonce_delta(), ONE);
 
     // Prove, serialize/deserialize and verify the transaction
     assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
@@ -181,6 +197,214 @@ fn prove_send_asset_via_wallet() {
     assert_eq!(executed_transaction.final_account().hash(), sender_account_after.hash());
 }
 
+#[test]
+// Testing the transaction builder - sending an asset
+fn send_asset_via_transaction_builder() {
+    let faucet_id_1 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset_1: Asset = FungibleAsset::new(faucet_id_1, 100).unwrap().into();
+
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let key_pair = KeyPair::new().unwrap();
+    let sender_pub_key: Word = key_pair.public_key().into();
+    let sender_keypair_felts: Vec<Felt> =
+        key_pair.to_bytes().iter().map(|byte| Felt::new(*byte as u64)).collect();
+    let sender_account = get_account_with_default_account_code(
+        sender_account_id,
+        sender_pub_key,
+        fungible_asset_1.into(),
+    );
+
+    let data_store = MockDataStore::with_existing(Some(sender_account.clone()), Some(vec![]));
+    let block_ref = data_store.block_header.block_num();
+
+    let recipient = [ZERO, ONE, Felt::new(2), Felt::new(3)];
+    let tag = Felt::new(4);
+    let tx_script_code = ProgramAst::parse(
+        format!(
+            "
+        use.miden::contracts::auth::basic->auth_tx
+        use.miden::contracts::wallets::basic->wallet
+
+        begin
+            push.{recipient}
+            push.{tag}
+            push.{asset}
+            call.wallet::send_asset drop
+            dropw dropw
+            call.auth_tx::auth_tx_rpo_falcon512
+        end
+        ",
+            recipient = prepare_word(&recipient),
+            tag = tag,
+            asset = prepare_word(&fungible_asset_1.into())
+        )
+        .as_str(),
+    )
+    .unwrap();
+
+    // execute the transaction manually
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(sender_account.id()).unwrap();
+    let tx_script = executor
+        .compile_tx_script(
+            tx_script_code.clone(),
+            vec![(sender_pub_key, sender_keypair_felts.clone())],
+            vec![],
+        )
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+    let manual_transaction = executor
+        .execute_transaction(sender_account.id(), block_ref, &[], Some(tx_args))
+        .unwrap();
+
+    // execute the same transaction via the builder
+    let mut executor = TransactionExecutor::new(data_store);
+    let built_transaction = TransactionBuilder::new(block_ref)
+        .account(sender_account.id())
+        .with_script(tx_script_code)
+        .with_auth(
+            AuthScheme::RpoFalcon512 { pub_key: key_pair.public_key() },
+            sender_keypair_felts,
+        )
+        .build(&mut executor)
+        .unwrap();
+
+    assert_eq!(built_transaction.id(), manual_transaction.id());
+    assert_eq!(built_transaction.program().hash(), manual_transaction.program().hash());
+    assert_eq!(built_transaction.final_account(), manual_transaction.final_account());
+    assert_eq!(built_transaction.account_delta(), manual_transaction.account_delta());
+}
+
+#[test]
+// Testing the compilation and execution of an intent to send an asset
+fn compile_and_execute_send_intent() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let (sender_pub_key, sender_keypair_felt) = get_new_key_pair_with_advice_map();
+    let sender_account =
+        get_account_with_default_account_code(sender_account_id, sender_pub_key, Some(asset));
+
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let serial_num = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    let intent = Intent::Send { to: target_account_id, asset, serial_num };
+
+    // compile the intent into a transaction program
+    let mut compiler = TransactionCompiler::new();
+    compiler.load_account_code(sender_account_id, sender_account.code().clone());
+    let (program, tx_script_root) =
+        compiler.compile_intent(sender_account_id, intent.clone()).unwrap();
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let data_store = MockDataStore::with_existing(Some(sender_account.clone()), Some(vec![]));
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(sender_account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+
+    let tx_script = executor
+        .compile_tx_script(
+            intent.tx_script().unwrap(),
+            vec![(sender_pub_key, sender_keypair_felt)],
+            vec![],
+        )
+        .unwrap();
+    assert_eq!(*tx_script.hash(), tx_script_root);
+
+    let executed_transaction = executor
+        .execute_transaction(
+            sender_account_id,
+            block_ref,
+            &[],
+            Some(TransactionArgs::with_tx_script(tx_script)),
+        )
+        .unwrap();
+    assert_eq!(executed_transaction.program().hash(), program.hash());
+
+    // the asset was sent to the target account via a P2ID note
+    let expected_note = create_p2id_note_with_serial_num(
+        sender_account_id,
+        target_account_id,
+        &[asset],
+        NoteType::OffChain,
+        serial_num,
+    )
+    .unwrap();
+    let output_notes = executed_transaction.output_notes();
+    assert_eq!(output_notes.num_notes(), 1);
+    assert_eq!(output_notes.get_note(0).id(), expected_note.id());
+    assert_eq!(output_notes.get_note(0).metadata(), expected_note.metadata());
+
+    let sender_account_after = Account::new(
+        sender_account_id,
+        AssetVault::new(&[]).unwrap(),
+        sender_account.storage().clone(),
+        sender_account.code().clone(),
+        Felt::new(2),
+    );
+    assert_eq!(executed_transaction.final_account().hash(), sender_account_after.hash());
+}
+
+#[test]
+// Testing the rotation of the authentication key of an account
+fn rotate_auth_key() {
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let (old_pub_key, old_keypair_felts) = get_new_key_pair_with_advice_map();
+    let account_storage =
+        AccountStorage::new(vec![(0, (StorageSlotType::Value { value_arity: 0 }, old_pub_key))])
+            .unwrap();
+    let account_code_src = "
+        use.miden::account
+        use.miden::contracts::auth::basic->auth_tx
+
+        export.auth_tx::auth_tx_rpo_falcon512
+
+        export.set_item
+            exec.account::set_item
+            movup.8 drop movup.8 drop movup.8 drop
+        end
+    ";
+    let account = Account::from_code_source(
+        account_id,
+        account_code_src,
+        &TransactionKernel::assembler(),
+        account_storage,
+        AssetVault::new(&[]).unwrap(),
+        ONE,
+    )
+    .unwrap();
+    assert_eq!(account.auth_public_key(), Some(old_pub_key));
+
+    let data_store = MockDataStore::with_existing(Some(account.clone()), Some(vec![]));
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    // the transaction is signed with the old key
+    let new_pub_key = KeyPair::new().unwrap().public_key();
+    let set_item_root = account.code().get_procedure_by_name("set_item").unwrap();
+    let tx_script_code = rotate_auth_key_script(set_item_root, new_pub_key);
+    let tx_script = executor
+        .compile_tx_script(tx_script_code, vec![(old_pub_key, old_keypair_felts)], vec![])
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    let executed_transaction = executor
+        .execute_transaction(account_id, block_ref, &note_ids, Some(tx_args))
+        .unwrap();
+
+    let mut final_account = account;
+    final_account.apply_delta(executed_transaction.account_delta()).unwrap();
+    assert_eq!(final_account.hash(), executed_transaction.final_account().hash());
+    assert_eq!(final_account.auth_public_key(), Some(Word::from(new_pub_key)));
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 #[test]
 fn wallet_creation() {
@@ -212,4 +436,180 @@ fn wallet_creation() {
     assert_eq!(wallet.code().root(), expected_code_root);
     let pub_key_word: Word = pub_key.into();
     assert_eq!(wallet.storage().get_item(0).as_elements(), pub_key_word);
+    assert_eq!(wallet.auth_public_key(), Some(pub_key_word));
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn wallet_creation_with_multiple_keys() {
+    use miden_objects::accounts::AccountType;
+
+    let hot_key_pair: KeyPair = KeyPair::new().unwrap();
+    let cold_key_pair: KeyPair = KeyPair::new().unwrap();
+    let auth_schemes = vec![
+        AuthScheme::RpoFalcon512 { pub_key: hot_key_pair.public_key() },
+        AuthScheme::RpoFalcon512 { pub_key: cold_key_pair.public_key() },
+    ];
+
+    let init_seed: [u8; 32] = [
+        95, 113, 209, 94, 84, 105, 250, 242, 223, 203, 216, 124, 22, 159, 14, 132, 215, 85, 183,
+        204, 149, 90, 166, 68, 100, 73, 106, 168, 125, 237, 138, 16,
+    ];
+
+    let (wallet, _) = create_basic_wallet_with_auth_schemes(
+        init_seed,
+        auth_schemes,
+        AccountType::RegularAccountImmutableCode,
+    )
+    .unwrap();
+
+    // both keys are stored in consecutive storage slots
+    let hot_pub_key: Word = hot_key_pair.public_key().into();
+    let cold_pub_key: Word = cold_key_pair.public_key().into();
+    assert_eq!(wallet.storage().get_item(0).as_elements(), hot_pub_key);
+    assert_eq!(wallet.storage().get_item(1).as_elements(), cold_pub_key);
+
+    // a transaction against the wallet can be authenticated with the second key
+    // --------------------------------------------------------------------------------------------
+    let wallet = Account::new(
+        wallet.id(),
+        wallet.vault().clone(),
+        wallet.storage().clone(),
+        wallet.code().clone(),
+        ONE,
+    );
+
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset = FungibleAsset::new(faucet_id, 100).unwrap();
+    let note_script_ast = ProgramAst::parse(
+        "
+        use.miden::note
+        use.miden::contracts::wallets::basic->wallet
+
+        begin
+            dropw
+            exec.note::get_assets drop
+            mem_loadw
+            call.wallet::receive_asset
+            dropw
+        end
+        ",
+    )
+    .unwrap();
+    let note = get_note_with_fungible_asset_and_script(fungible_asset, note_script_ast);
+
+    let data_store = MockDataStore::with_existing(Some(wallet.clone()), Some(vec![note]));
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(wallet.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+    let tx_script_code = ProgramAst::parse(
+        "
+        use.miden::contracts::auth::basic->auth_tx
+
+        begin
+            # authenticate the transaction with the key stored at index 1
+            push.1
+            call.auth_tx::auth_tx_rpo_falcon512_multi
+            drop
+        end
+        ",
+    )
+    .unwrap();
+    let cold_key_pair_felts = cold_key_pair
+        .to_bytes()
+        .iter()
+        .map(|byte| Felt::new(*byte as u64))
+        .collect::<Vec<_>>();
+    let tx_script = executor
+        .compile_tx_script(tx_script_code, vec![(cold_pub_key, cold_key_pair_felts)], vec![])
+        .unwrap();
+
+    let executed_transaction = executor
+        .execute_transaction(
+            wallet.id(),
+            block_ref,
+            &note_ids,
+            Some(TransactionArgs::with_tx_script(tx_script)),
+        )
+        .unwrap();
+    assert_eq!(executed_transaction.account_delta().nonce(), Some(Felt::new(2)));
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn create_and_fund_wallet() {
+    use miden_objects::accounts::AccountType;
+
+    let faucet_key_pair: KeyPair = KeyPair::new().unwrap();
+    let (faucet, _) = create_basic_fungible_faucet(
+        [1; 32],
+        TokenSymbol::try_from("POL").unwrap(),
+        2,
+        Felt::new(1000),
+        AuthScheme::RpoFalcon512 { pub_key: faucet_key_pair.public_key() },
+    )
+    .unwrap();
+
+    let key_pair: KeyPair = KeyPair::new().unwrap();
+    let pub_key: Word = key_pair.public_key().into();
+    let (wallet, _, note) = create_and_fund_account(
+        [2; 32],
+        AuthScheme::RpoFalcon512 { pub_key: key_pair.public_key() },
+        AccountType::RegularAccountImmutableCode,
+        &faucet,
+        100,
+    )
+    .unwrap();
+
+    // the funding note is sent by the faucet to the new wallet
+    let asset: Asset = FungibleAsset::new(faucet.id(), 100).unwrap().into();
+    assert_eq!(note.metadata().sender(), faucet.id());
+    assert_eq!(note.assets().iter().copied().collect::<Vec<_>>(), vec![asset]);
+
+    // consume the funding note with the wallet
+    // --------------------------------------------------------------------------------------------
+    let wallet = Account::new(
+        wallet.id(),
+        wallet.vault().clone(),
+        wallet.storage().clone(),
+        wallet.code().clone(),
+        ONE,
+    );
+
+    let data_store = MockDataStore::with_existing(Some(wallet.clone()), Some(vec![note]));
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(wallet.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let key_pair_felts = key_pair
+        .to_bytes()
+        .iter()
+        .map(|byte| Felt::new(*byte as u64))
+        .collect::<Vec<_>>();
+    let tx_script = executor
+        .compile_tx_script(
+            ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap(),
+            vec![(pub_key, key_pair_felts)],
+            vec![],
+        )
+        .unwrap();
+
+    let executed_transaction = executor
+        .execute_transaction(
+            wallet.id(),
+            block_ref,
+            &note_ids,
+            Some(TransactionArgs::with_tx_script(tx_script)),
+        )
+        .unwrap();
+
+    // the wallet holds the funded amount
+    assert_eq!(
+        executed_transaction.final_account().vault_root(),
+        AssetVault::new(&[asset]).unwrap().commitment()
+    );
 }
@@ -47,7 +47,7 @@ pub fn run_tx_with_inputs(
     inputs: AdviceInputs,
 ) -> Result<Process<MockHost>, ExecutionError> {
     let program = tx.program().clone();
-    let (stack_inputs, mut advice_inputs) = tx.get_kernel_inputs();
+    let (stack_inputs, mut advice_inputs) = tx.get_kernel_inputs().unwrap();
     advice_inputs.extend(inputs);
     let host = MockHost::new(tx.account().into(), advice_inputs);
     let exec_options = ExecutionOptions::default().with_tracing();
@@ -151,17 +151,15 @@ pub fn mock_fungible_faucet(
     empty_reserved_slot: bool,
     assembler: &Assembler,
 ) -> Account {
-    let initial_balance = if empty_reserved_slot {
-        ZERO
-    } else {
-        Felt::new(FUNGIBLE_FAUCET_INITIAL_BALANCE)
-    };
-    let account_storage = AccountStorage::new(vec![(
-        FAUCET_STORAGE_DATA_SLOT,
-        (StorageSlotType::Value { value_arity: 0 }, [ZERO, ZERO, ZERO, initial_balance]),
-    )])
-    .unwrap();
     let account_id = AccountId::try_from(account_id).unwrap();
+    let mut account_storage =
+        AccountStorage::with_template(account_id.account_type(), vec![]).unwrap();
+    if !empty_reserved_slot {
+        let initial_balance = Felt::new(FUNGIBLE_FAUCET_INITIAL_BALANCE);
+        account_storage
+            .set_item(FAUCET_STORAGE_DATA_SLOT, [ZERO, ZERO, ZERO, initial_balance])
+            .unwrap();
+    }
     let account_code = mock_account_code(assembler);
     Account::new(account_id, AssetVault::default(), account_storage, account_code, nonce)
 }
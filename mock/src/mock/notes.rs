@@ -25,6 +25,21 @@ pub enum AssetPreservationStatus {
     TooManyNonFungibleInput,
 }
 
+/// Returns a note without inputs which carries the specified assets and is locked by the
+/// specified script.
+///
+/// # Panics
+/// Panics if the note cannot be created from the provided assets (e.g., the list of assets is
+/// empty or contains duplicates).
+pub fn mock_note_with_assets(
+    sender: AccountId,
+    assets: &[Asset],
+    script: NoteScript,
+    serial_num: Word,
+) -> Note {
+    Note::new(script, &[], assets, serial_num, sender, ZERO).unwrap()
+}
+
 pub fn mock_notes(
     assembler: &Assembler,
     asset_preservation: &AssetPreservationStatus,
@@ -104,10 +119,10 @@ pub fn mock_notes(
         end
     ",
         created_note_0_recipient = prepare_word(&created_notes[0].recipient()),
-        created_note_0_tag = created_notes[0].metadata().tag(),
+        created_note_0_tag = created_notes[0].metadata().tag().inner(),
         created_note_0_asset = prepare_assets(created_notes[0].assets())[0],
         created_note_1_recipient = prepare_word(&created_notes[1].recipient()),
-        created_note_1_tag = created_notes[1].metadata().tag(),
+        created_note_1_tag = created_notes[1].metadata().tag().inner(),
         created_note_1_asset = prepare_assets(created_notes[1].assets())[0],
     );
     let note_1_script_ast = ProgramAst::parse(&note_1_script_src).unwrap();
@@ -127,7 +142,7 @@ pub fn mock_notes(
         end
         ",
         created_note_2_recipient = prepare_word(&created_notes[2].recipient()),
-        created_note_2_tag = created_notes[2].metadata().tag(),
+        created_note_2_tag = created_notes[2].metadata().tag().inner(),
         created_note_2_asset = prepare_assets(created_notes[2].assets())[0],
     );
     let note_2_script_ast = ProgramAst::parse(&note_2_script_src).unwrap();
@@ -210,19 +225,16 @@ pub fn mock_notes(
     .unwrap();
     let (note_5_script, _) = NoteScript::new(note_5_script_ast, assembler).unwrap();
     const SERIAL_NUM_8: Word = [Felt::new(29), Felt::new(30), Felt::new(31), Felt::new(32)];
-    let consumed_note_5 = Note::new(
-        note_5_script,
-        &[],
+    let consumed_note_5 = mock_note_with_assets(
+        sender,
         &[
             fungible_asset_1,
             fungible_asset_3,
             non_fungible_asset_2(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN),
         ],
+        note_5_script,
         SERIAL_NUM_8,
-        sender,
-        ZERO,
-    )
-    .unwrap();
+    );
 
     let consumed_notes = match asset_preservation {
         AssetPreservationStatus::TooFewInput => vec![consumed_note_1],
@@ -1,4 +1,4 @@
-use core::fmt;
+use core::{fmt, str::FromStr};
 
 use super::{
     get_account_seed, Account, AccountError, ByteReader, Deserializable, DeserializationError,
@@ -23,6 +23,44 @@ pub enum AccountType {
     RegularAccountUpdatableCode,
 }
 
+impl AccountType {
+    /// Returns true if this is a faucet account type (i.e., an account which can issue assets).
+    pub fn is_faucet(&self) -> bool {
+        matches!(self, Self::FungibleFaucet | Self::NonFungibleFaucet)
+    }
+
+    /// Returns true if this is a regular account type.
+    pub fn is_regular(&self) -> bool {
+        matches!(self, Self::RegularAccountImmutableCode | Self::RegularAccountUpdatableCode)
+    }
+}
+
+impl fmt::Display for AccountType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::FungibleFaucet => "fungible-faucet",
+            Self::NonFungibleFaucet => "non-fungible-faucet",
+            Self::RegularAccountImmutableCode => "regular-immutable-code",
+            Self::RegularAccountUpdatableCode => "regular-updatable-code",
+        };
+        f.write_str(name)
+    }
+}
+
+impl FromStr for AccountType {
+    type Err = AccountError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "fungible-faucet" => Ok(Self::FungibleFaucet),
+            "non-fungible-faucet" => Ok(Self::NonFungibleFaucet),
+            "regular-immutable-code" => Ok(Self::RegularAccountImmutableCode),
+            "regular-updatable-code" => Ok(Self::RegularAccountUpdatableCode),
+            _ => Err(AccountError::AccountTypeInvalid(s.to_string())),
+        }
+    }
+}
+
 /// Unique identifier of an account.
 ///
 /// Account ID consists of 1 field element (~64 bits). This field element uniquely identifies a
@@ -33,9 +71,11 @@ pub enum AccountType {
 ///  - 10 - fungible asset faucet with immutable code.
 ///  - 11 - non-fungible asset faucet with immutable code.
 /// - The third most significant bit of the ID specifies whether the account data is stored on-chain:
-///  - 0 - full account data is stored on-chain.
-///  - 1 - only the account hash is stored on-chain which serves as a commitment to the account state.
+///  - 1 - full account data is stored on-chain.
+///  - 0 - only the account hash is stored on-chain which serves as a commitment to the account state.
 /// As such the three most significant bits fully describes the type of the account.
+///
+/// See [AccountIdLayout] for accessing the individual bit fields of an ID.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "serde", serde(transparent))]
@@ -98,6 +138,41 @@ impl AccountId {
         Ok(id)
     }
 
+    /// Returns a new account ID assembled from the specified fields.
+    ///
+    /// The type and storage mode bits are derived from `account_type` and `on_chain`, while the
+    /// remaining bits of the ID are set to `rest` (see [AccountIdLayout]). The returned ID is not
+    /// derived from a seed, and thus, cannot be used to create a new account; this is useful for
+    /// assembling IDs of existing accounts or IDs used in tests.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `rest` does not fit into [AccountIdLayout::REST_BITS] bits.
+    /// - The resulting ID is not a valid field element or has fewer than 5 ONEs.
+    pub fn from_fields(
+        account_type: AccountType,
+        on_chain: bool,
+        rest: u64,
+    ) -> Result<Self, AccountError> {
+        if rest > AccountIdLayout::REST_MASK {
+            return Err(AccountError::AccountIdInvalidRest(rest));
+        }
+
+        let type_bits = match account_type {
+            AccountType::FungibleFaucet => Self::FUNGIBLE_FAUCET_TAG,
+            AccountType::NonFungibleFaucet => Self::NON_FUNGIBLE_FAUCET_TAG,
+            AccountType::RegularAccountImmutableCode => Self::REGULAR_ACCOUNT_IMMUTABLE_CODE_TAG,
+            AccountType::RegularAccountUpdatableCode => Self::REGULAR_ACCOUNT_UPDATABLE_CODE_TAG,
+        };
+        let storage_mode_bits = on_chain as u64 & Self::ON_CHAIN_ACCOUNT_SELECTOR;
+
+        Self::try_from(
+            type_bits << AccountIdLayout::TYPE_BITS_OFFSET
+                | storage_mode_bits << AccountIdLayout::STORAGE_MODE_BITS_OFFSET
+                | rest,
+        )
+    }
+
     /// Creates a new [AccountId] without checking its validity.
     ///
     /// This function requires that the provided value is a valid [Felt] representation of an
@@ -132,20 +207,19 @@ impl AccountId {
         }
     }
 
+    /// Returns the bit fields of this account ID.
+    pub fn layout(&self) -> AccountIdLayout {
+        AccountIdLayout(self.0.as_int())
+    }
+
     /// Returns true if an account with this ID is a faucet (can issue assets).
     pub fn is_faucet(&self) -> bool {
-        matches!(
-            self.account_type(),
-            AccountType::FungibleFaucet | AccountType::NonFungibleFaucet
-        )
+        self.account_type().is_faucet()
     }
 
     /// Returns true if an account with this ID is a regular account.
     pub fn is_regular_account(&self) -> bool {
-        matches!(
-            self.account_type(),
-            AccountType::RegularAccountUpdatableCode | AccountType::RegularAccountImmutableCode
-        )
+        self.account_type().is_regular()
     }
 
     /// Returns true if an account with this ID is an on-chain account.
@@ -229,6 +303,47 @@ impl AccountId {
     }
 }
 
+// ACCOUNT ID LAYOUT
+// ================================================================================================
+
+/// Bit fields of an [AccountId].
+///
+/// From the most significant bit, an account ID consists of:
+/// - 2 type bits which specify the [AccountType] of the account (see [AccountId] for the encoding).
+/// - 1 storage mode bit which is set to 1 for on-chain accounts and to 0 for off-chain accounts.
+/// - 61 remaining bits which are derived from the account seed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccountIdLayout(u64);
+
+impl AccountIdLayout {
+    /// Offset of the type bits from the least significant bit of the ID.
+    pub const TYPE_BITS_OFFSET: u32 = 62;
+
+    /// Offset of the storage mode bit from the least significant bit of the ID.
+    pub const STORAGE_MODE_BITS_OFFSET: u32 = 61;
+
+    /// Number of the remaining bits of the ID.
+    pub const REST_BITS: u32 = 61;
+
+    /// Mask selecting the remaining bits of the ID.
+    pub const REST_MASK: u64 = (1 << Self::REST_BITS) - 1;
+
+    /// Returns the two type bits of the account ID.
+    pub fn type_bits(&self) -> u64 {
+        self.0 >> Self::TYPE_BITS_OFFSET
+    }
+
+    /// Returns the storage mode bit of the account ID; 1 for on-chain and 0 for off-chain accounts.
+    pub fn storage_mode_bits(&self) -> u64 {
+        self.0 >> Self::STORAGE_MODE_BITS_OFFSET & AccountId::ON_CHAIN_ACCOUNT_SELECTOR
+    }
+
+    /// Returns the remaining bits of the account ID.
+    pub fn rest(&self) -> u64 {
+        self.0 & Self::REST_MASK
+    }
+}
+
 impl PartialOrd for AccountId {
     fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         Some(self.cmp(other))
@@ -370,8 +485,9 @@ mod tests {
             ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
             ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
         },
-        AccountId, AccountType,
+        AccountError, AccountId, AccountIdLayout, AccountType, FromStr,
     };
+    use crate::utils::string::*;
 
     #[test]
     fn test_from_hex_and_back() {
@@ -407,4 +523,78 @@ mod tests {
         assert_eq!(account_id.account_type(), AccountType::NonFungibleFaucet);
         assert!(!account_id.is_on_chain());
     }
+
+    #[test]
+    fn test_account_type_display_from_str() {
+        let account_types = [
+            AccountType::FungibleFaucet,
+            AccountType::NonFungibleFaucet,
+            AccountType::RegularAccountImmutableCode,
+            AccountType::RegularAccountUpdatableCode,
+        ];
+        for account_type in account_types {
+            assert_eq!(AccountType::from_str(&account_type.to_string()).unwrap(), account_type);
+        }
+
+        assert!(matches!(
+            AccountType::from_str("faucet"),
+            Err(AccountError::AccountTypeInvalid(name)) if name == "faucet"
+        ));
+    }
+
+    #[test]
+    fn test_account_type_predicates() {
+        assert!(AccountType::FungibleFaucet.is_faucet());
+        assert!(AccountType::NonFungibleFaucet.is_faucet());
+        assert!(!AccountType::RegularAccountImmutableCode.is_faucet());
+        assert!(!AccountType::RegularAccountUpdatableCode.is_faucet());
+
+        assert!(!AccountType::FungibleFaucet.is_regular());
+        assert!(!AccountType::NonFungibleFaucet.is_regular());
+        assert!(AccountType::RegularAccountImmutableCode.is_regular());
+        assert!(AccountType::RegularAccountUpdatableCode.is_regular());
+    }
+
+    #[test]
+    fn test_account_id_layout() {
+        let ids = [
+            (
+                ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+                AccountType::RegularAccountImmutableCode,
+                0b01,
+                true,
+            ),
+            (
+                ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+                AccountType::RegularAccountUpdatableCode,
+                0b00,
+                false,
+            ),
+            (ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, AccountType::FungibleFaucet, 0b10, true),
+            (
+                ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN,
+                AccountType::NonFungibleFaucet,
+                0b11,
+                false,
+            ),
+        ];
+
+        for (id, account_type, type_bits, on_chain) in ids {
+            let account_id = AccountId::try_from(id).unwrap();
+            let layout = account_id.layout();
+            assert_eq!(layout.type_bits(), type_bits);
+            assert_eq!(layout.storage_mode_bits(), on_chain as u64);
+            assert_eq!(layout.rest(), id & AccountIdLayout::REST_MASK);
+
+            let reassembled =
+                AccountId::from_fields(account_type, on_chain, layout.rest()).unwrap();
+            assert_eq!(reassembled, account_id);
+        }
+
+        // the remaining bits must not overlap with the type and storage mode bits
+        assert_eq!(
+            AccountId::from_fields(AccountType::FungibleFaucet, true, 1 << 61),
+            Err(AccountError::AccountIdInvalidRest(1 << 61))
+        );
+    }
 }
@@ -136,6 +136,23 @@ impl AccountCode {
     pub fn get_procedure_index_by_root(&self, root: Digest) -> Option<usize> {
         self.procedures.iter().position(|r| r == &root)
     }
+
+    /// Returns the digest of the exported procedure with the specified name or None if such
+    /// procedure is not exported from the module of this account.
+    ///
+    /// Procedure digests are ordered in the same way as the assembler exports them: re-exported
+    /// procedures come first, followed by the exported local procedures in declaration order.
+    pub fn get_procedure_by_name(&self, name: &str) -> Option<Digest> {
+        let reexported_names = self.module.reexported_procs().iter().map(|proc| proc.name());
+        let local_names =
+            self.module.procs().iter().filter(|proc| proc.is_export).map(|proc| &proc.name);
+
+        reexported_names
+            .chain(local_names)
+            .zip(self.procedures.iter())
+            .find(|(proc_name, _)| proc_name.as_ref() == name)
+            .map(|(_, &root)| root)
+    }
 }
 
 // EQUALITY
@@ -225,4 +242,29 @@ mod tests {
         let code2 = AccountCode::read_from_bytes(&bytes).unwrap();
         assert_eq!(code1, code2)
     }
+
+    #[test]
+    fn get_procedure_by_name() {
+        let source = "
+            proc.helper
+                push.1 push.2 sub
+            end
+
+            export.foo
+                push.1 push.2 mul
+            end
+
+            export.bar
+                exec.helper
+            end
+        ";
+
+        let module = ModuleAst::parse(source).unwrap();
+        let code = AccountCode::new(module, &Assembler::default()).unwrap();
+
+        assert_eq!(code.get_procedure_by_name("foo"), Some(code.procedures()[0]));
+        assert_eq!(code.get_procedure_by_name("bar"), Some(code.procedures()[1]));
+        assert_eq!(code.get_procedure_by_name("helper"), None);
+        assert_eq!(code.get_procedure_by_name("baz"), None);
+    }
 }
@@ -2,7 +2,10 @@ use super::{
     AccountDeltaError, ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
     Word,
 };
-use crate::utils::{collections::*, string::*};
+use crate::{
+    accounts::AccountStorage,
+    utils::{collections::*, string::*},
+};
 
 // CONSTANTS
 // ================================================================================================
@@ -23,6 +26,22 @@ pub struct AccountStorageDelta {
 }
 
 impl AccountStorageDelta {
+    /// Returns a new [AccountStorageDelta] instantiated from the provided cleared and updated
+    /// items.
+    ///
+    /// # Errors
+    /// Returns an error if the resulting delta is invalid (see [AccountStorageDelta::validate()]).
+    /// In particular, a slot may not appear in both lists, or more than once in either list, as
+    /// the result of applying such a delta would depend on the order of the updates.
+    pub fn new(
+        cleared_items: Vec<u8>,
+        updated_items: Vec<(u8, Word)>,
+    ) -> Result<Self, AccountDeltaError> {
+        let delta = Self { cleared_items, updated_items };
+        delta.validate()?;
+        Ok(delta)
+    }
+
     /// Checks whether this storage delta is valid.
     ///
     /// # Errors
@@ -79,6 +98,30 @@ impl AccountStorageDelta {
     pub fn is_empty(&self) -> bool {
         self.cleared_items.is_empty() && self.updated_items.is_empty()
     }
+
+    /// Returns the delta which reverts the changes made by this delta when applied to the
+    /// provided storage.
+    ///
+    /// The provided storage must be the state of the storage before this delta was applied. Every
+    /// slot touched by this delta is set back to its value in the prior storage: slots which were
+    /// empty are cleared, and all other slots are updated with their prior values.
+    pub fn invert(&self, prior: &AccountStorage) -> AccountStorageDelta {
+        let mut cleared_items = Vec::new();
+        let mut updated_items = Vec::new();
+
+        let touched_items =
+            self.cleared_items.iter().chain(self.updated_items.iter().map(|(idx, _)| idx));
+        for &idx in touched_items {
+            let prior_value = prior.get_item_elements(idx);
+            if prior_value == Word::default() {
+                cleared_items.push(idx);
+            } else {
+                updated_items.push((idx, prior_value));
+            }
+        }
+
+        Self { cleared_items, updated_items }
+    }
 }
 
 impl Serializable for AccountStorageDelta {
@@ -163,8 +206,10 @@ impl Deserializable for AccountStorageDelta {
 
 #[cfg(test)]
 mod tests {
-    use super::{AccountStorageDelta, Deserializable, Serializable};
-    use crate::{ONE, ZERO};
+    use super::{
+        AccountDeltaError, AccountStorage, AccountStorageDelta, Deserializable, Serializable,
+    };
+    use crate::{accounts::StorageSlotType, Felt, ONE, ZERO};
 
     #[test]
     fn account_storage_delta_validation() {
@@ -231,4 +276,63 @@ mod tests {
         let bytes = delta.to_bytes();
         assert!(AccountStorageDelta::read_from_bytes(&bytes).is_err());
     }
+
+    #[test]
+    fn account_storage_delta_new() {
+        let delta = AccountStorageDelta::new(vec![1, 2], vec![(3, [ONE, ONE, ONE, ONE])]).unwrap();
+        assert_eq!(delta.cleared_items, vec![1, 2]);
+        assert_eq!(delta.updated_items, vec![(3, [ONE, ONE, ONE, ONE])]);
+
+        // slot both cleared and updated
+        assert_eq!(
+            AccountStorageDelta::new(vec![1, 2], vec![(2, [ONE, ONE, ONE, ONE])]),
+            Err(AccountDeltaError::DuplicateStorageItemUpdate(2))
+        );
+
+        // duplicate cleared slot
+        assert_eq!(
+            AccountStorageDelta::new(vec![1, 2, 1], vec![]),
+            Err(AccountDeltaError::DuplicateStorageItemUpdate(1))
+        );
+
+        // duplicate updated slot
+        assert_eq!(
+            AccountStorageDelta::new(
+                vec![],
+                vec![(3, [ONE, ONE, ONE, ONE]), (3, [ONE, ONE, ONE, ZERO])]
+            ),
+            Err(AccountDeltaError::DuplicateStorageItemUpdate(3))
+        );
+    }
+
+    #[test]
+    fn account_storage_delta_invert() {
+        let value_1 = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let value_2 = [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)];
+        let prior = AccountStorage::new(vec![
+            (0, (StorageSlotType::default(), value_1)),
+            (1, (StorageSlotType::default(), value_2)),
+        ])
+        .unwrap();
+
+        // clear-then-invert restores the cleared values; clearing an empty slot is a no-op
+        let delta = AccountStorageDelta::new(vec![0, 2], vec![]).unwrap();
+        let inverse = delta.invert(&prior);
+        assert_eq!(inverse, AccountStorageDelta::new(vec![2], vec![(0, value_1)]).unwrap());
+
+        let mut storage = prior.clone();
+        storage.apply_delta(&delta).unwrap();
+        storage.apply_delta(&inverse).unwrap();
+        assert_eq!(storage.root(), prior.root());
+
+        // update-then-invert restores prior values and clears slots which were empty
+        let delta = AccountStorageDelta::new(vec![], vec![(1, value_1), (3, value_2)]).unwrap();
+        let inverse = delta.invert(&prior);
+        assert_eq!(inverse, AccountStorageDelta::new(vec![3], vec![(1, value_2)]).unwrap());
+
+        let mut storage = prior.clone();
+        storage.apply_delta(&delta).unwrap();
+        storage.apply_delta(&inverse).unwrap();
+        assert_eq!(storage.root(), prior.root());
+    }
 }
@@ -1,12 +1,14 @@
 use crate::{
     assembly::{Assembler, AssemblyContext, ModuleAst},
-    assets::AssetVault,
+    assets::{Asset, AssetVault, FungibleAsset},
     utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
     AccountError, Digest, Felt, FieldElement, Hasher, Word, ZERO,
 };
 
 mod account_id;
-pub use account_id::{compute_digest, digest_pow, validate_account_seed, AccountId, AccountType};
+pub use account_id::{
+    compute_digest, digest_pow, validate_account_seed, AccountId, AccountIdLayout, AccountType,
+};
 
 mod code;
 pub use code::AccountCode;
@@ -18,7 +20,12 @@ mod seed;
 pub use seed::{get_account_seed, get_account_seed_single};
 
 mod storage;
-pub use storage::{AccountStorage, SlotItem, StorageSlotType};
+#[cfg(feature = "debug-log")]
+pub use storage::StorageMutation;
+pub use storage::{
+    layout_commitment_of, AccountStorage, LazyAccountStorage, SlotItem, StorageLayoutBuilder,
+    StorageSlotKind, StorageSlotType,
+};
 
 mod stub;
 pub use stub::AccountStub;
@@ -89,6 +96,25 @@ impl Account {
         Self { id, vault, storage, code, nonce }
     }
 
+    /// Creates and returns a new account initialized with the specified ID, vault, storage, and
+    /// nonce, and with the code compiled from the provided source using the provided assembler.
+    ///
+    /// # Errors
+    /// Returns an error if parsing or compilation of the account code fails.
+    pub fn from_code_source(
+        id: AccountId,
+        code_source: &str,
+        assembler: &Assembler,
+        storage: AccountStorage,
+        vault: AssetVault,
+        nonce: Felt,
+    ) -> Result<Self, AccountError> {
+        let module = ModuleAst::parse(code_source)
+            .map_err(|err| AccountError::AccountCodeAssemblerError(err.into()))?;
+        let code = AccountCode::new(module, assembler)?;
+        Ok(Self::new(id, vault, storage, code, nonce))
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -106,6 +132,45 @@ impl Account {
         )
     }
 
+    /// Returns the hash this account would have if the specified components were replaced with
+    /// the provided values.
+    ///
+    /// Components for which `None` is provided are taken from this account. The account itself
+    /// is not modified, which makes this method suitable for previewing the effects of an update
+    /// (e.g., of applying a delta) on the account hash.
+    pub fn hash_with_overrides(
+        &self,
+        nonce: Option<Felt>,
+        vault_root: Option<Digest>,
+        storage_root: Option<Digest>,
+        code_root: Option<Digest>,
+    ) -> Digest {
+        hash_account(
+            self.id,
+            nonce.unwrap_or(self.nonce),
+            vault_root.unwrap_or_else(|| self.vault.commitment()),
+            storage_root.unwrap_or_else(|| self.storage.root()),
+            code_root.unwrap_or_else(|| self.code.root()),
+        )
+    }
+
+    /// Returns true if an account with the specified ID, component roots, and nonce has the
+    /// `expected` hash.
+    ///
+    /// This allows verifying that account components received from an untrusted source (e.g., a
+    /// full node) are consistent with a known account hash without holding the full account
+    /// state.
+    pub fn verify_against_hash(
+        id: AccountId,
+        vault_root: Digest,
+        storage_root: Digest,
+        code_root: Digest,
+        nonce: Felt,
+        expected: Digest,
+    ) -> bool {
+        hash_account(id, nonce, vault_root, storage_root, code_root) == expected
+    }
+
     /// Returns hash of this account as used for the initial account state hash in transaction
     /// proofs.
     ///
@@ -152,6 +217,21 @@ impl Account {
         self.nonce
     }
 
+    /// Returns the public key used to authenticate transactions against this account.
+    ///
+    /// Standard accounts store their RPO Falcon512 public key (i.e., the commitment to the
+    /// expanded key) as a value in the [AccountStorage::AUTH_PUBLIC_KEY_SLOT_INDEX] slot. None is
+    /// returned if this slot is empty or is not a value slot.
+    pub fn auth_public_key(&self) -> Option<Word> {
+        let index = AccountStorage::AUTH_PUBLIC_KEY_SLOT_INDEX;
+        if self.storage.layout()[index as usize] != (StorageSlotType::Value { value_arity: 0 }) {
+            return None;
+        }
+
+        let pub_key = self.storage.get_item_elements(index);
+        (pub_key != Word::default()).then_some(pub_key)
+    }
+
     /// Returns true if this account can issue assets.
     pub fn is_faucet(&self) -> bool {
         self.id.is_faucet()
@@ -172,6 +252,62 @@ impl Account {
         self.nonce == ZERO
     }
 
+    /// Returns the delta which transforms this account into the provided account.
+    ///
+    /// The delta contains all storage slots whose values differ between the two accounts, the
+    /// assets which need to be added to or removed from the vault of this account, and the nonce
+    /// of the provided account if it differs from the nonce of this account. Changes to the
+    /// account code and to the storage layout cannot be expressed by [AccountDelta] and are
+    /// ignored.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The IDs of the two accounts are different.
+    /// - The nonce of the provided account is smaller than the nonce of this account.
+    /// - The state of the account changed, but the nonce was not incremented.
+    pub fn delta_to(&self, other: &Account) -> Result<AccountDelta, AccountError> {
+        if self.id != other.id {
+            return Err(AccountError::InconsistentAccountId {
+                expected: self.id,
+                actual: other.id,
+            });
+        }
+
+        if other.nonce.as_int() < self.nonce.as_int() {
+            return Err(AccountError::NonceNotMonotonicallyIncreasing {
+                current: self.nonce.as_int(),
+                new: other.nonce.as_int(),
+            });
+        }
+
+        // compute storage delta
+        let mut cleared_items = Vec::new();
+        let mut updated_items = Vec::new();
+        for index in 0..=AccountStorage::max_slot_index() {
+            let new_value = other.storage.get_item_elements(index);
+            if self.storage.get_item_elements(index) != new_value {
+                if new_value == Word::default() {
+                    cleared_items.push(index);
+                } else {
+                    updated_items.push((index, new_value));
+                }
+            }
+        }
+        let storage_delta = AccountStorageDelta::new(cleared_items, updated_items)
+            .map_err(AccountError::AccountDeltaInvalid)?;
+
+        // compute vault delta
+        let vault_delta = AccountVaultDelta {
+            added_assets: missing_assets(&self.vault, &other.vault)?,
+            removed_assets: missing_assets(&other.vault, &self.vault)?,
+        };
+
+        let nonce = (other.nonce != self.nonce).then_some(other.nonce);
+
+        AccountDelta::new(storage_delta, vault_delta, nonce)
+            .map_err(AccountError::AccountDeltaInvalid)
+    }
+
     // DATA MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -225,11 +361,18 @@ impl Account {
         Ok(())
     }
 
-    // TEST HELPERS
-    // --------------------------------------------------------------------------------------------
+    /// Returns a mutable reference to the storage of this account.
+    ///
+    /// Modifying the storage changes the storage root and thus the hash of this account.
+    pub fn storage_mut(&mut self) -> &mut AccountStorage {
+        &mut self.storage
+    }
 
-    #[cfg(test)]
     /// Returns a mutable reference to the vault of this account.
+    ///
+    /// Modifying the vault changes the vault root and thus the hash of this account. For the new
+    /// state to be valid on-chain, the caller is also responsible for incrementing the nonce via
+    /// [Account::set_nonce()].
     pub fn vault_mut(&mut self) -> &mut AssetVault {
         &mut self.vault
     }
@@ -303,20 +446,55 @@ pub fn hash_account(
     Hasher::hash_elements(&elements)
 }
 
+/// Returns the assets which are present in the `target` vault but not in the `source` vault.
+///
+/// For fungible assets, only the amount by which the balance in the `target` vault exceeds the
+/// balance in the `source` vault is returned.
+fn missing_assets(source: &AssetVault, target: &AssetVault) -> Result<Vec<Asset>, AccountError> {
+    let mut assets = Vec::new();
+    for asset in target.assets() {
+        match asset {
+            Asset::Fungible(fungible_asset) => {
+                let faucet_id = fungible_asset.faucet_id();
+                let source_balance =
+                    source.get_balance(faucet_id).map_err(AccountError::AssetVaultUpdateError)?;
+                if fungible_asset.amount() > source_balance {
+                    let amount = fungible_asset.amount() - source_balance;
+                    let asset = FungibleAsset::new(faucet_id, amount)
+                        .expect("amount is smaller than the amount of a valid asset");
+                    assets.push(asset.into());
+                }
+            },
+            Asset::NonFungible(_) => {
+                if !source
+                    .has_non_fungible_asset(asset)
+                    .map_err(AccountError::AssetVaultUpdateError)?
+                {
+                    assets.push(asset);
+                }
+            },
+        }
+    }
+    Ok(assets)
+}
+
 // TESTS
 // ================================================================================================
 
 #[cfg(test)]
 mod tests {
     use super::{
-        Account, AccountCode, AccountDelta, AccountId, AccountStorage, AccountStorageDelta,
-        AccountVaultDelta, Assembler, Felt, ModuleAst, SlotItem, StorageSlotType, Word,
-        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
-        ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+        hash_account, Account, AccountCode, AccountDelta, AccountError, AccountId, AccountStorage,
+        AccountStorageDelta, AccountVaultDelta, Assembler, Digest, Felt, ModuleAst, SlotItem,
+        StorageSlotType, Word, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2, ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
     };
     use crate::{
         assets::{Asset, AssetVault, FungibleAsset},
-        utils::collections::*,
+        utils::{
+            collections::*,
+            serde::{Deserializable, Serializable},
+        },
     };
 
     fn build_account(assets: Vec<Asset>, nonce: Felt, storage_items: Vec<Word>) -> Account {
@@ -397,6 +575,99 @@ mod tests {
         assert_eq!(account, final_account);
     }
 
+    #[test]
+    fn account_hash_with_overrides() {
+        let word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let (asset_0, _) = build_assets();
+        let account = build_account(vec![asset_0], Felt::new(1), vec![Word::default()]);
+
+        // without overrides the hash is the hash of the account
+        assert_eq!(account.hash_with_overrides(None, None, None, None), account.hash());
+
+        // overriding the nonce matches mutating the account
+        let mut updated_account = account.clone();
+        updated_account.set_nonce(Felt::new(2)).unwrap();
+        assert_eq!(
+            account.hash_with_overrides(Some(Felt::new(2)), None, None, None),
+            updated_account.hash()
+        );
+
+        // overriding the storage root matches mutating the account
+        updated_account.storage_mut().set_item(0, word).unwrap();
+        assert_eq!(
+            account.hash_with_overrides(
+                Some(Felt::new(2)),
+                None,
+                Some(updated_account.storage().root()),
+                None
+            ),
+            updated_account.hash()
+        );
+
+        // the account itself is not modified
+        assert_eq!(account.nonce(), Felt::new(1));
+        assert_ne!(account.hash(), updated_account.hash());
+    }
+
+    #[test]
+    fn account_verify_against_hash() {
+        let (asset_0, _) = build_assets();
+        let account = build_account(vec![asset_0], Felt::new(1), vec![Word::default()]);
+        let vault_root = account.vault().commitment();
+        let storage_root = account.storage().root();
+        let code_root = account.code().root();
+        let verify = |vault_root, storage_root, code_root, nonce| {
+            Account::verify_against_hash(
+                account.id(),
+                vault_root,
+                storage_root,
+                code_root,
+                nonce,
+                account.hash(),
+            )
+        };
+
+        assert!(verify(vault_root, storage_root, code_root, account.nonce()));
+
+        // tampering with any of the components fails the verification
+        let tampered = Digest::from([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+        assert!(!verify(tampered, storage_root, code_root, account.nonce()));
+        assert!(!verify(vault_root, tampered, code_root, account.nonce()));
+        assert!(!verify(vault_root, storage_root, tampered, account.nonce()));
+        assert!(!verify(vault_root, storage_root, code_root, Felt::new(2)));
+    }
+
+    #[test]
+    fn account_storage_and_nonce_can_be_updated_in_place() {
+        let init_nonce = Felt::new(1);
+        let word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let mut account = build_account(vec![], init_nonce, vec![Word::default()]);
+        let init_hash = account.hash();
+
+        // update a storage slot and the nonce
+        account.storage_mut().set_item(0, word).unwrap();
+        account.set_nonce(Felt::new(2)).unwrap();
+
+        // the account hash should match the one of an account built from the updated data
+        let final_account = build_account(vec![], Felt::new(2), vec![word]);
+        assert_ne!(account.hash(), init_hash);
+        assert_eq!(account.hash(), final_account.hash());
+        assert_eq!(
+            account.hash(),
+            hash_account(
+                account.id(),
+                account.nonce(),
+                account.vault().commitment(),
+                account.storage().root(),
+                account.code().root()
+            )
+        );
+
+        // the nonce can only be incremented
+        assert!(account.set_nonce(Felt::new(2)).is_err());
+        assert!(account.set_nonce(Felt::new(1)).is_err());
+    }
+
     #[test]
     #[should_panic]
     fn valid_account_delta_with_unchanged_nonce() {
@@ -448,4 +719,151 @@ mod tests {
         // apply delta
         account.apply_delta(&account_delta).unwrap()
     }
+
+    #[test]
+    fn account_delta_to_transforms_account() {
+        let (asset_0, asset_1) = build_assets();
+        let faucet_id_0 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let init_account = build_account(vec![asset_0], Felt::new(1), vec![word, Word::default()]);
+
+        // remove part of asset 0, add asset 1, clear slot 0 and update slot 1
+        let asset_0_remainder: Asset = FungibleAsset::new(faucet_id_0, 100).unwrap().into();
+        let final_account = build_account(
+            vec![asset_0_remainder, asset_1],
+            Felt::new(2),
+            vec![Word::default(), word],
+        );
+
+        let delta = init_account.delta_to(&final_account).unwrap();
+        assert_eq!(delta.nonce(), Some(Felt::new(2)));
+        assert_eq!(delta.storage().cleared_items, vec![0]);
+        assert_eq!(delta.storage().updated_items, vec![(1, word)]);
+        assert_eq!(delta.vault().added_assets, vec![asset_1]);
+        assert_eq!(
+            delta.vault().removed_assets,
+            vec![FungibleAsset::new(faucet_id_0, 23).unwrap().into()]
+        );
+
+        let mut account = init_account.clone();
+        account.apply_delta(&delta).unwrap();
+        assert_eq!(account, final_account);
+
+        // an account without changes results in an empty delta
+        assert_eq!(init_account.delta_to(&init_account).unwrap(), AccountDelta::default());
+    }
+
+    #[test]
+    fn account_delta_to_rejects_invalid_transitions() {
+        let (asset_0, _) = build_assets();
+        let init_account = build_account(vec![asset_0], Felt::new(2), vec![]);
+
+        // nonce went backwards
+        let account = build_account(vec![], Felt::new(1), vec![]);
+        assert_eq!(
+            init_account.delta_to(&account),
+            Err(AccountError::NonceNotMonotonicallyIncreasing { current: 2, new: 1 })
+        );
+
+        // state changed without a nonce increment
+        let account = build_account(vec![], Felt::new(2), vec![]);
+        assert!(matches!(
+            init_account.delta_to(&account),
+            Err(AccountError::AccountDeltaInvalid(_))
+        ));
+    }
+
+    #[test]
+    fn account_serialization() {
+        let (asset_0, asset_1) = build_assets();
+        let word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let account = build_account(vec![asset_0, asset_1], Felt::new(3), vec![word]);
+
+        let bytes = account.to_bytes();
+        let deserialized = Account::read_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized, account);
+        assert_eq!(deserialized.hash(), account.hash());
+
+        // account with a map slot in its storage
+        let storage = AccountStorage::new(vec![
+            (0, (StorageSlotType::Value { value_arity: 0 }, word)),
+            (1, (StorageSlotType::Map { value_arity: 0 }, [Felt::new(5); 4])),
+        ])
+        .unwrap();
+        let account = Account::new(
+            account.id(),
+            account.vault().clone(),
+            storage,
+            account.code().clone(),
+            Felt::new(3),
+        );
+
+        let bytes = account.to_bytes();
+        let deserialized = Account::read_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized, account);
+        assert_eq!(deserialized.storage().layout(), account.storage().layout());
+        assert_eq!(deserialized.hash(), account.hash());
+    }
+
+    #[test]
+    fn account_from_code_source() {
+        let source = "
+            export.foo
+                push.1 push.2 mul
+            end
+        ";
+        let assembler = Assembler::default();
+        let id = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let storage = AccountStorage::new(vec![]).unwrap();
+        let vault = AssetVault::new(&[]).unwrap();
+
+        let account =
+            Account::from_code_source(id, source, &assembler, storage, vault, Felt::new(1))
+                .unwrap();
+        let code = AccountCode::new(ModuleAst::parse(source).unwrap(), &assembler).unwrap();
+        assert_eq!(account.code().root(), code.root());
+
+        // invalid source is rejected
+        let storage = AccountStorage::new(vec![]).unwrap();
+        let vault = AssetVault::new(&[]).unwrap();
+        assert!(matches!(
+            Account::from_code_source(id, "export.foo", &assembler, storage, vault, Felt::new(1)),
+            Err(AccountError::AccountCodeAssemblerError(_))
+        ));
+    }
+
+    #[test]
+    fn account_vault_mut() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+        let storage_items = vec![[Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]];
+
+        let mut account = build_account(vec![], Felt::new(1), storage_items.clone());
+        account.vault_mut().add_asset(asset).unwrap();
+        account.set_nonce(Felt::new(2)).unwrap();
+
+        let expected = build_account(vec![asset], Felt::new(2), storage_items);
+        assert_eq!(account.hash(), expected.hash());
+        assert_eq!(account, expected);
+    }
+
+    #[test]
+    fn account_auth_public_key() {
+        let pub_key = [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)];
+        let account = build_account(vec![], Felt::new(1), vec![pub_key]);
+        assert_eq!(account.auth_public_key(), Some(pub_key));
+
+        // an empty slot does not hold a public key
+        let account = build_account(vec![], Felt::new(1), vec![]);
+        assert_eq!(account.auth_public_key(), None);
+
+        // neither does a slot which is not a value slot
+        let mut account = build_account(vec![], Felt::new(1), vec![]);
+        account.storage = AccountStorage::new(vec![(
+            AccountStorage::AUTH_PUBLIC_KEY_SLOT_INDEX,
+            (StorageSlotType::Map { value_arity: 0 }, pub_key),
+        )])
+        .unwrap();
+        assert_eq!(account.auth_public_key(), None);
+    }
 }
@@ -0,0 +1,85 @@
+use super::{AccountError, AccountStorage, SlotItem, StorageSlotType, Word};
+use crate::{crypto::merkle::Smt, utils::collections::*};
+
+// STORAGE LAYOUT BUILDER
+// ================================================================================================
+
+/// A builder which assigns storage slots to the items of an account storage.
+///
+/// Slots are handed out sequentially starting from slot 0, so the same sequence of allocations
+/// always results in the same storage layout. Slots after [AccountStorage::max_slot_index()] are
+/// reserved and are never allocated.
+#[derive(Debug, Clone, Default)]
+pub struct StorageLayoutBuilder {
+    items: Vec<SlotItem>,
+}
+
+impl StorageLayoutBuilder {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new [StorageLayoutBuilder] with no slots allocated.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // ALLOCATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Allocates the next free slot for an item of the specified type and returns its index.
+    ///
+    /// Map slots are initialized with the root of an empty map, all other slots are initialized
+    /// with [ZERO; 4].
+    ///
+    /// # Errors
+    /// Returns an error if all non-reserved slots have already been allocated.
+    pub fn allocate(&mut self, slot_type: StorageSlotType) -> Result<u8, AccountError> {
+        let value = if slot_type.is_map() {
+            Smt::default().root().into()
+        } else {
+            Word::default()
+        };
+        self.allocate_with_value(slot_type, value)
+    }
+
+    /// Allocates the next free slot for an item of the specified type initialized with the
+    /// provided value and returns its index.
+    ///
+    /// # Errors
+    /// Returns an error if all non-reserved slots have already been allocated.
+    pub fn allocate_with_value(
+        &mut self,
+        slot_type: StorageSlotType,
+        value: Word,
+    ) -> Result<u8, AccountError> {
+        let index = self.items.len();
+        if index > AccountStorage::max_slot_index() as usize {
+            return Err(AccountError::StorageSlotsExhausted);
+        }
+
+        let index = index as u8;
+        self.items.push((index, (slot_type, value)));
+        Ok(index)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of slots allocated so far.
+    pub fn num_allocated(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns the type of the slot at the specified index, or None if the slot has not been
+    /// allocated.
+    pub fn slot_type(&self, index: u8) -> Option<StorageSlotType> {
+        self.items.get(index as usize).map(|(_, (slot_type, _))| *slot_type)
+    }
+
+    // BUILDER
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the allocated slot items, which can be used to construct [AccountStorage].
+    pub fn build(self) -> Vec<SlotItem> {
+        self.items
+    }
+}
@@ -0,0 +1,119 @@
+use alloc::string::ToString;
+use core::cell::OnceCell;
+
+use super::{
+    AccountStorage, DeserializationError, Digest, SimpleSmt, SlotItem, StorageSlotType, Word,
+    STORAGE_TREE_DEPTH,
+};
+use crate::utils::{collections::*, format};
+
+// LAZY ACCOUNT STORAGE
+// ================================================================================================
+
+/// Account storage whose storage tree is built on first use.
+///
+/// Lazy storage is returned by [AccountStorage::read_lazy()]. Slot values and the storage layout
+/// can be read right away, while the storage tree (and thus the storage root) is computed only
+/// once it is first needed, i.e., by [LazyAccountStorage::root()],
+/// [LazyAccountStorage::storage()], or [LazyAccountStorage::into_storage()].
+#[derive(Debug, Clone)]
+pub struct LazyAccountStorage {
+    entries: BTreeMap<u64, Word>,
+    layout: Vec<StorageSlotType>,
+    storage: OnceCell<AccountStorage>,
+}
+
+impl LazyAccountStorage {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new [LazyAccountStorage] initialized with the provided items.
+    ///
+    /// Since the storage tree is built later on, all errors which the tree construction could
+    /// report are detected here.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Any of the items is placed in a reserved slot.
+    /// - Multiple items are placed in the same slot.
+    pub(super) fn new(items: Vec<SlotItem>) -> Result<Self, DeserializationError> {
+        let (layout, tree_entries) = AccountStorage::build_layout(items)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+
+        let mut entries = BTreeMap::new();
+        for (index, value) in tree_entries {
+            if entries.insert(index, value).is_some() {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "duplicate value for storage slot {index}"
+                )));
+            }
+        }
+
+        Ok(Self {
+            entries,
+            layout,
+            storage: OnceCell::new(),
+        })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns an item from the storage at the specified index.
+    ///
+    /// This does not build the storage tree.
+    pub fn get_item(&self, index: u8) -> Digest {
+        self.entries
+            .get(&(index as u64))
+            .copied()
+            .unwrap_or(SimpleSmt::<STORAGE_TREE_DEPTH>::EMPTY_VALUE)
+            .into()
+    }
+
+    /// Returns layout info for this storage.
+    pub fn layout(&self) -> &[StorageSlotType] {
+        &self.layout
+    }
+
+    /// Returns a commitment to this storage.
+    ///
+    /// The storage tree is built when this method is called for the first time.
+    pub fn root(&self) -> Digest {
+        self.storage().root()
+    }
+
+    /// Returns the fully constructed account storage, building the storage tree if needed.
+    pub fn storage(&self) -> &AccountStorage {
+        self.storage.get_or_init(|| build_storage(&self.entries, self.layout.clone()))
+    }
+
+    /// Returns true if the storage tree has already been built.
+    pub fn is_built(&self) -> bool {
+        self.storage.get().is_some()
+    }
+
+    // CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Converts this lazy storage into [AccountStorage], building the storage tree if needed.
+    pub fn into_storage(self) -> AccountStorage {
+        let Self { entries, layout, storage } = self;
+        storage.into_inner().unwrap_or_else(|| build_storage(&entries, layout))
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Builds account storage with the specified layout from the provided storage tree entries.
+///
+/// The entries are assumed to have been validated when the lazy storage was created.
+fn build_storage(entries: &BTreeMap<u64, Word>, layout: Vec<StorageSlotType>) -> AccountStorage {
+    let slots = SimpleSmt::with_leaves(entries.iter().map(|(&index, &value)| (index, value)))
+        .expect("storage entries were validated");
+    AccountStorage {
+        slots,
+        layout,
+        #[cfg(feature = "debug-log")]
+        mutation_log: Vec::new(),
+    }
+}
@@ -1,14 +1,21 @@
 use super::{
-    AccountError, AccountStorageDelta, ByteReader, ByteWriter, Deserializable,
+    AccountError, AccountStorageDelta, AccountType, ByteReader, ByteWriter, Deserializable,
     DeserializationError, Digest, Felt, Hasher, Serializable, Word,
 };
 use crate::{
-    crypto::merkle::{LeafIndex, NodeIndex, SimpleSmt},
-    utils::{collections::*, string::*, vec},
+    crypto::merkle::{LeafIndex, SimpleSmt, Smt},
+    utils::{collections::*, string::*, vec, SliceReader},
+    WORD_SIZE,
 };
 
+mod layout;
+pub use layout::StorageLayoutBuilder;
+
+mod lazy;
+pub use lazy::LazyAccountStorage;
+
 mod slot;
-pub use slot::StorageSlotType;
+pub use slot::{StorageSlotKind, StorageSlotType};
 
 // CONSTANTS
 // ================================================================================================
@@ -28,6 +35,10 @@ pub type SlotItem = (u8, StorageSlot);
 /// structure.
 pub type StorageSlot = (StorageSlotType, Word);
 
+/// A type that represents a single entry of the storage tree. The tuple contains the index of the
+/// leaf and the value stored in it.
+type TreeEntry = (u64, Word);
+
 // ACCOUNT STORAGE
 // ================================================================================================
 
@@ -42,10 +53,16 @@ pub type StorageSlot = (StorageSlotType, Word);
 ///
 /// Storage slots are stored in a simple Sparse Merkle Tree of depth 8. Slot 255 is always reserved
 /// and contains information about slot types of all other slots.
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// With the `debug-log` feature enabled, the storage also keeps a log of all slot updates (see
+/// [AccountStorage::mutation_log()]). The log is not a part of the storage state, and thus it is
+/// neither serialized nor considered when comparing storages.
+#[derive(Debug, Clone)]
 pub struct AccountStorage {
     slots: SimpleSmt<STORAGE_TREE_DEPTH>,
     layout: Vec<StorageSlotType>,
+    #[cfg(feature = "debug-log")]
+    mutation_log: Vec<StorageMutation>,
 }
 
 impl AccountStorage {
@@ -61,22 +78,68 @@ impl AccountStorage {
     /// The storage slot at which the layout commitment is stored.
     pub const SLOT_LAYOUT_COMMITMENT_INDEX: u8 = 255;
 
+    /// The storage slot at which standard accounts store the public key used for authentication.
+    pub const AUTH_PUBLIC_KEY_SLOT_INDEX: u8 = 0;
+
+    /// The storage slot at which faucet accounts store their data.
+    ///
+    /// Fungible faucets store the total issuance as a value in this slot, while non-fungible
+    /// faucets store the root of a map containing the issued assets.
+    pub const FAUCET_DATA_SLOT_INDEX: u8 = 254;
+
+    /// Returns the index of the last storage slot which can be used by the account.
+    ///
+    /// All slots after this index are reserved.
+    pub const fn max_slot_index() -> u8 {
+        Self::SLOT_LAYOUT_COMMITMENT_INDEX - 1
+    }
+
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
     /// Returns a new instance of account storage initialized with the provided items.
     pub fn new(items: Vec<SlotItem>) -> Result<AccountStorage, AccountError> {
-        // initialize storage layout
-        let mut layout = vec![StorageSlotType::default(); Self::NUM_STORAGE_SLOTS];
+        let (layout, entries) = Self::build_layout(items)?;
 
-        // set the slot type for the layout commitment
-        layout[Self::SLOT_LAYOUT_COMMITMENT_INDEX as usize] =
-            StorageSlotType::Value { value_arity: 64 };
+        // construct storage slots smt
+        let slots = SimpleSmt::<STORAGE_TREE_DEPTH>::with_leaves(entries)
+            .map_err(AccountError::DuplicateStorageItems)?;
+
+        Ok(Self {
+            slots,
+            layout,
+            #[cfg(feature = "debug-log")]
+            mutation_log: Vec::new(),
+        })
+    }
+
+    /// Reads account storage from the provided bytes without constructing the storage tree.
+    ///
+    /// The returned [LazyAccountStorage] provides access to the slot values right away, while the
+    /// storage tree is built only once it is needed (e.g., by [LazyAccountStorage::root()]).
+    ///
+    /// # Errors
+    /// Returns an error if the bytes are not a valid serialization of account storage.
+    pub fn read_lazy(bytes: &[u8]) -> Result<LazyAccountStorage, DeserializationError> {
+        let items = Self::read_items(&mut SliceReader::new(bytes))?;
+        LazyAccountStorage::new(items)
+    }
+
+    /// Returns the layout of a storage initialized with the provided items, together with the
+    /// entries of its storage tree (including the layout commitment entry).
+    ///
+    /// # Errors
+    /// Returns an error if any of the items is placed in a reserved slot.
+    fn build_layout(
+        items: Vec<SlotItem>,
+    ) -> Result<(Vec<StorageSlotType>, Vec<TreeEntry>), AccountError> {
+        // initialize storage layout
+        let mut layout = Self::default_layout();
 
         // process entries to extract type data
         let mut entires = items
             .into_iter()
             .map(|x| {
-                if x.0 == Self::SLOT_LAYOUT_COMMITMENT_INDEX {
+                if x.0 > Self::max_slot_index() {
                     return Err(AccountError::StorageSlotIsReserved(x.0));
                 }
 
@@ -87,16 +150,91 @@ impl AccountStorage {
             .collect::<Result<Vec<_>, AccountError>>()?;
 
         // add layout commitment entry
-        entires.push((
-            Self::SLOT_LAYOUT_COMMITMENT_INDEX as u64,
-            *Hasher::hash_elements(&layout.iter().map(Felt::from).collect::<Vec<_>>()),
-        ));
+        entires.push((Self::SLOT_LAYOUT_COMMITMENT_INDEX as u64, *layout_commitment_of(&layout)));
 
-        // construct storage slots smt and populate the types vector.
-        let slots = SimpleSmt::<STORAGE_TREE_DEPTH>::with_leaves(entires)
-            .map_err(AccountError::DuplicateStorageItems)?;
+        Ok((layout, entires))
+    }
+
+    /// Returns a new instance of account storage reconstructed from a snapshot produced by
+    /// [AccountStorage::snapshot()].
+    ///
+    /// `slots` contains the index, type and value of every slot in the snapshot, while `maps`
+    /// contains the underlying maps of some (or all) of the map slots. Each provided map is checked
+    /// against the value of its slot; since account storage keeps only commitments to maps, the
+    /// maps are not retained by the returned storage.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Any of the slots is a reserved slot, or the slots cannot be placed into storage.
+    /// - A map is provided for a slot which is not a map slot.
+    /// - The root of a provided map is different from the value of its slot.
+    pub fn from_snapshot(
+        slots: Vec<(u8, StorageSlotType, Word)>,
+        maps: Vec<(u8, Smt)>,
+    ) -> Result<AccountStorage, AccountError> {
+        let storage = Self::new(
+            slots
+                .into_iter()
+                .map(|(index, slot_type, value)| (index, (slot_type, value)))
+                .collect(),
+        )?;
+
+        for (index, map) in maps {
+            let slot_type = storage.layout.get(index as usize).copied().unwrap_or_default();
+            if !slot_type.is_map() {
+                return Err(AccountError::StorageSlotNotMapSlot(index, slot_type));
+            }
 
-        Ok(Self { slots, layout })
+            let expected = storage.get_item(index);
+            if map.root() != expected {
+                return Err(AccountError::StorageMapRootMismatch {
+                    slot: index,
+                    expected,
+                    actual: map.root(),
+                });
+            }
+        }
+
+        Ok(storage)
+    }
+
+    /// Returns a new instance of account storage for an account of the specified type.
+    ///
+    /// For faucet accounts, the faucet data slot is initialized to an empty value of the type
+    /// expected by the faucet, i.e., a value slot for fungible faucets and a map slot for
+    /// non-fungible faucets. The provided user slots are added to the storage as in
+    /// [AccountStorage::new()].
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Any of the user slots is placed in a reserved slot, including the faucet data slot for
+    ///   faucet accounts.
+    /// - The storage cannot be constructed from the provided slots.
+    pub fn with_template(
+        account_type: AccountType,
+        user_slots: Vec<SlotItem>,
+    ) -> Result<AccountStorage, AccountError> {
+        let faucet_slot = match account_type {
+            AccountType::FungibleFaucet => {
+                Some((StorageSlotType::Value { value_arity: 0 }, Word::default()))
+            },
+            AccountType::NonFungibleFaucet => {
+                Some((StorageSlotType::Map { value_arity: 0 }, Smt::default().root().into()))
+            },
+            AccountType::RegularAccountImmutableCode | AccountType::RegularAccountUpdatableCode => {
+                None
+            },
+        };
+
+        let mut items = user_slots;
+        if let Some(faucet_slot) = faucet_slot {
+            if items.iter().any(|(index, _)| *index == Self::FAUCET_DATA_SLOT_INDEX) {
+                return Err(AccountError::StorageSlotIsReserved(Self::FAUCET_DATA_SLOT_INDEX));
+            }
+            items.push((Self::FAUCET_DATA_SLOT_INDEX, faucet_slot));
+        }
+
+        Self::new(items)
     }
 
     // PUBLIC ACCESSORS
@@ -111,9 +249,27 @@ impl AccountStorage {
     ///
     /// If the item is not present in the storage, [ZERO; 4] is returned.
     pub fn get_item(&self, index: u8) -> Digest {
-        let item_index = NodeIndex::new(Self::STORAGE_TREE_DEPTH, index as u64)
-            .expect("index is u8 - index within range");
-        self.slots.get_node(item_index).expect("index is u8 - index within range")
+        match Self::leaf_index(index) {
+            Ok(leaf_index) => self.slots.get_leaf(&leaf_index).into(),
+            Err(_) => SimpleSmt::<STORAGE_TREE_DEPTH>::EMPTY_VALUE.into(),
+        }
+    }
+
+    /// Returns the elements of the item stored at the specified index.
+    ///
+    /// This is the counterpart of [AccountStorage::set_item()], which accepts a [Word]. If the
+    /// item is not present in the storage, [ZERO; 4] is returned.
+    pub fn get_item_elements(&self, index: u8) -> Word {
+        self.get_item(index).into()
+    }
+
+    /// Returns the items stored at the specified indices, in the order in which the indices were
+    /// provided.
+    ///
+    /// Same as for [AccountStorage::get_item()], empty slots are returned as [ZERO; 4] and the
+    /// reserved slot returns the storage layout commitment.
+    pub fn get_items(&self, indices: &[u8]) -> Vec<Word> {
+        indices.iter().map(|&index| self.get_item_elements(index)).collect()
     }
 
     /// Returns a reference to the Sparse Merkle Tree that backs the storage slots.
@@ -126,9 +282,74 @@ impl AccountStorage {
         &self.layout
     }
 
+    /// Returns the log of all updates made to the storage slots, in the order they were made.
+    #[cfg(feature = "debug-log")]
+    pub fn mutation_log(&self) -> &[StorageMutation] {
+        &self.mutation_log
+    }
+
     /// Returns a commitment to the storage layout.
     pub fn layout_commitment(&self) -> Digest {
-        Hasher::hash_elements(&self.layout.iter().map(Felt::from).collect::<Vec<_>>())
+        layout_commitment_of(&self.layout)
+    }
+
+    /// Returns indices of all non-reserved slots which have the default type and an empty value.
+    pub fn free_slots(&self) -> Vec<u8> {
+        (0..=Self::max_slot_index())
+            .filter(|&index| {
+                self.layout[index as usize].is_default()
+                    && self.get_item(index) == SimpleSmt::<STORAGE_TREE_DEPTH>::EMPTY_VALUE.into()
+            })
+            .collect()
+    }
+
+    /// Returns a flat representation of this storage which can be used to reconstruct it via
+    /// [AccountStorage::from_snapshot()].
+    ///
+    /// The snapshot contains the index, type and value of every non-reserved slot which has a
+    /// non-default type or holds a non-empty value, in ascending slot order.
+    pub fn snapshot(&self) -> Vec<(u8, StorageSlotType, Word)> {
+        (0..=Self::max_slot_index())
+            .filter_map(|index| {
+                let slot_type = self.layout[index as usize];
+                let value = self.get_item_elements(index);
+                (!slot_type.is_default() || value != SimpleSmt::<STORAGE_TREE_DEPTH>::EMPTY_VALUE)
+                    .then_some((index, slot_type, value))
+            })
+            .collect()
+    }
+
+    /// Returns the number of non-reserved slots which hold a non-empty value.
+    pub fn occupied_slots(&self) -> usize {
+        self.filled_slots().count()
+    }
+
+    /// Returns true if none of the non-reserved slots hold a non-empty value.
+    ///
+    /// The reserved layout commitment slot is always populated and is not taken into account.
+    pub fn is_empty(&self) -> bool {
+        self.filled_slots().next().is_none()
+    }
+
+    /// Returns the number of field elements occupied by the commitments stored in the non-reserved
+    /// slots of this storage.
+    ///
+    /// Each slot which holds a non-empty value contributes a single word. This is the commitment
+    /// footprint only: map and array slots (as well as value slots with non-zero arity) hold a
+    /// commitment to the underlying data, which is not kept by the account storage, so the size of
+    /// that data is not included.
+    pub fn commitment_size_in_felts(&self) -> usize {
+        self.filled_slots().count() * WORD_SIZE
+    }
+
+    /// Returns true if the slot at the specified index can be updated via
+    /// [AccountStorage::set_item()].
+    ///
+    /// Currently, only value slots of arity 0 can be updated. Reserved slots, value slots of
+    /// higher arity as well as map and array slots are not mutable in place.
+    pub fn is_mutable_slot(&self, index: u8) -> bool {
+        index <= Self::max_slot_index()
+            && matches!(self.layout[index as usize], StorageSlotType::Value { value_arity: 0 })
     }
 
     // DATA MUTATORS
@@ -162,8 +383,64 @@ impl AccountStorage {
     /// - The index specifies a reserved storage slot.
     /// - The update violates storage layout constraints.
     pub fn set_item(&mut self, index: u8, value: Word) -> Result<Word, AccountError> {
-        // layout commitment slot cannot be updated
-        if index == Self::SLOT_LAYOUT_COMMITMENT_INDEX {
+        let leaf_index = self.validate_update(index)?;
+
+        // update the slot and return
+        let slot_value = self.write_slot(leaf_index, value);
+        Ok(slot_value)
+    }
+
+    /// Sets the items at the specified indexes to the provided values.
+    ///
+    /// All updates are validated before any of them is applied, so either all items are updated
+    /// or, if an error is returned, the storage is left unchanged. If the same index is listed
+    /// more than once, the last value for it is written.
+    ///
+    /// # Errors
+    /// Returns an error if any of the updates would fail as described in
+    /// [AccountStorage::set_item()].
+    pub fn set_items(&mut self, items: &[(u8, Word)]) -> Result<(), AccountError> {
+        let updates = items
+            .iter()
+            .map(|&(index, value)| Ok((self.validate_update(index)?, value)))
+            .collect::<Result<Vec<_>, AccountError>>()?;
+
+        for (leaf_index, value) in updates {
+            self.write_slot(leaf_index, value);
+        }
+        Ok(())
+    }
+
+    /// Writes the value into the storage slot at the specified leaf and returns the previous value
+    /// of the slot.
+    ///
+    /// With the `debug-log` feature enabled, the update is also recorded in the mutation log.
+    fn write_slot(&mut self, leaf_index: LeafIndex<STORAGE_TREE_DEPTH>, value: Word) -> Word {
+        let old_value = self.slots.insert(leaf_index, value);
+
+        #[cfg(feature = "debug-log")]
+        self.mutation_log.push(StorageMutation {
+            index: leaf_index.value() as u8,
+            old_value,
+            new_value: value,
+        });
+
+        old_value
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks that the slot at the specified index can be updated and returns the index of the
+    /// storage tree leaf which holds it.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The index specifies a reserved storage slot.
+    /// - The slot is not a value slot of arity 0.
+    fn validate_update(&self, index: u8) -> Result<LeafIndex<STORAGE_TREE_DEPTH>, AccountError> {
+        // reserved slots cannot be updated
+        if index > Self::max_slot_index() {
             return Err(AccountError::StorageSlotIsReserved(index));
         }
 
@@ -181,13 +458,168 @@ impl AccountStorage {
             slot_type => Err(AccountError::StorageSlotNotValueSlot(index, slot_type))?,
         }
 
-        // update the slot and return
-        let index = LeafIndex::new(index as u64).expect("index is u8 - index within range");
-        let slot_value = self.slots.insert(index, value);
-        Ok(slot_value)
+        Self::leaf_index(index)
+    }
+
+    /// Returns an iterator over the slots which hold a non-empty value, excluding the reserved
+    /// layout commitment slot.
+    fn filled_slots(&self) -> impl Iterator<Item = (u64, &Word)> {
+        self.slots.leaves().filter(|(idx, &value)| {
+            value != SimpleSmt::<STORAGE_TREE_DEPTH>::EMPTY_VALUE
+                && *idx as u8 != Self::SLOT_LAYOUT_COMMITMENT_INDEX
+        })
+    }
+
+    /// Returns the slots which need to be serialized, excluding the reserved layout commitment
+    /// slot.
+    ///
+    /// These are the slots which hold a non-empty value as well as all slots with a non-default
+    /// type; the latter are included even if their value is empty (e.g., a map slot whose root is
+    /// the empty word) so that they are not confused with unset value slots.
+    fn serialized_slots(&self) -> Vec<(u8, Word)> {
+        (0..=Self::max_slot_index())
+            .map(|idx| (idx, self.get_item_elements(idx)))
+            .filter(|(idx, value)| {
+                *value != SimpleSmt::<STORAGE_TREE_DEPTH>::EMPTY_VALUE
+                    || !self.layout[*idx as usize].is_default()
+            })
+            .collect()
+    }
+
+    /// Returns the layout of a storage in which all non-reserved slots have the default type.
+    fn default_layout() -> Vec<StorageSlotType> {
+        let mut layout = vec![StorageSlotType::default(); Self::NUM_STORAGE_SLOTS];
+
+        // set the slot type for the layout commitment
+        layout[Self::SLOT_LAYOUT_COMMITMENT_INDEX as usize] =
+            StorageSlotType::Value { value_arity: 64 };
+
+        layout
+    }
+
+    /// Reads the slot items of a serialized account storage.
+    ///
+    /// The table of complex slot types is validated to be in the form produced by the serializer:
+    /// slot indices must be strictly increasing and must not refer to reserved slots, and only
+    /// non-default slot types may be listed. Any other table would result in a layout (and thus a
+    /// layout commitment) different from the one of the serialized storage.
+    ///
+    /// The layout described by the table is also checked against the serialized layout
+    /// commitment. Thus, if the type of a slot was lost (e.g., the type of a map slot was omitted
+    /// from the table), an error is returned instead of silently treating the slot as a value
+    /// slot. Similarly, every slot listed in the table must have a serialized value (the serializer
+    /// writes the values of all slots with a complex type, even if they are empty).
+    fn read_items<R: ByteReader>(source: &mut R) -> Result<Vec<SlotItem>, DeserializationError> {
+        // read complex types
+        let mut complex_types = BTreeMap::new();
+        let num_complex_types = source.read_u8()?;
+        for _ in 0..num_complex_types {
+            let idx = source.read_u8()?;
+            let slot_type: StorageSlotType =
+                source.read_u16()?.try_into().map_err(DeserializationError::InvalidValue)?;
+
+            if idx > Self::max_slot_index() {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "slot type of reserved storage slot {idx} must not be serialized"
+                )));
+            }
+            if complex_types.last_key_value().is_some_and(|(&last_idx, _)| last_idx >= idx) {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "slot type of storage slot {idx} is not in ascending slot order"
+                )));
+            }
+            if slot_type.is_default() {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "default slot type of storage slot {idx} must not be serialized"
+                )));
+            }
+            complex_types.insert(idx, slot_type);
+        }
+
+        let mut layout = Self::default_layout();
+        for (&idx, &slot_type) in complex_types.iter() {
+            layout[idx as usize] = slot_type;
+        }
+
+        // read filled slots and build a vector of slot items
+        let mut items: Vec<SlotItem> = Vec::new();
+        let num_filled_slots = source.read_u8()?;
+        for _ in 0..num_filled_slots {
+            let idx = source.read_u8()?;
+            let slot_value: Word = source.read()?;
+            let slot_type = complex_types.remove(&idx).unwrap_or_default();
+            items.push((idx, (slot_type, slot_value)));
+        }
+
+        // without a value, the type of a slot would be lost when building the storage
+        if let Some(idx) = complex_types.keys().next() {
+            return Err(DeserializationError::InvalidValue(format!(
+                "value of storage slot {idx} with a complex type is missing"
+            )));
+        }
+
+        // make sure the types of all slots were serialized
+        let layout_commitment = Digest::read_from(source)?;
+        if layout_commitment_of(&layout) != layout_commitment {
+            return Err(DeserializationError::InvalidValue(
+                "storage layout does not match the layout commitment; the type of a storage slot \
+                 is missing or altered"
+                    .to_string(),
+            ));
+        }
+
+        Ok(items)
+    }
+
+    /// Returns the index of the storage tree leaf which holds the specified slot.
+    ///
+    /// # Errors
+    /// Returns an error if the slot index does not fit into the storage tree.
+    fn leaf_index(index: u8) -> Result<LeafIndex<STORAGE_TREE_DEPTH>, AccountError> {
+        debug_assert!(
+            STORAGE_TREE_DEPTH as u32 >= u8::BITS,
+            "storage tree must be able to hold all slot indices"
+        );
+        LeafIndex::new(index as u64).map_err(|_| AccountError::StorageSlotIndexOutOfBounds(index))
+    }
+}
+
+impl PartialEq for AccountStorage {
+    fn eq(&self, other: &Self) -> bool {
+        // the mutation log is not a part of the storage state
+        self.slots == other.slots && self.layout == other.layout
     }
 }
 
+impl Eq for AccountStorage {}
+
+// STORAGE MUTATION
+// ================================================================================================
+
+/// An update of a single storage slot, as recorded in the mutation log of [AccountStorage].
+#[cfg(feature = "debug-log")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StorageMutation {
+    /// Index of the updated slot.
+    pub index: u8,
+    /// Value of the slot before the update.
+    pub old_value: Word,
+    /// Value of the slot after the update.
+    pub new_value: Word,
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Returns a commitment to the provided storage layout.
+///
+/// This is the only place where the hash function used for the layout commitment is chosen. The
+/// storage tree itself, as well as the trees backing storage maps, are hashed with RPO since this
+/// is the hash function expected by the transaction kernel, and thus cannot be changed here.
+pub fn layout_commitment_of(layout: &[StorageSlotType]) -> Digest {
+    Hasher::hash_elements(&layout.iter().map(Felt::from).collect::<Vec<_>>())
+}
+
 // SERIALIZATION
 // ================================================================================================
 
@@ -208,48 +640,27 @@ impl Serializable for AccountStorage {
             target.write_u16(slot_type.into());
         }
 
-        // serialize slot values; we serialize only non-empty values and also skip slot 255 as info
-        // for this slot was already serialized as a part of serializing slot type info above
-        let filled_slots = self
-            .slots
-            .leaves()
-            .filter(|(idx, &value)| {
-                // TODO: consider checking empty values for complex types as well
-                value != SimpleSmt::<STORAGE_TREE_DEPTH>::EMPTY_VALUE
-                    && *idx as u8 != AccountStorage::SLOT_LAYOUT_COMMITMENT_INDEX
-            })
-            .collect::<Vec<_>>();
+        // serialize slot values; we serialize non-empty values as well as the values of all slots
+        // with a complex type, and skip slot 255 as info for this slot was already serialized as a
+        // part of serializing slot type info above
+        let serialized_slots = self.serialized_slots();
 
-        target.write_u8(filled_slots.len() as u8);
-        for (idx, &value) in filled_slots {
-            target.write_u8(idx as u8);
+        target.write_u8(serialized_slots.len() as u8);
+        for (idx, value) in serialized_slots {
+            target.write_u8(idx);
             target.write(value);
         }
+
+        // serialize the layout commitment so that a lost or altered slot type can be detected;
+        // note that this is not a part of the format used by earlier versions, and thus, storage
+        // serialized by earlier versions cannot be deserialized
+        self.layout_commitment().write_into(target);
     }
 }
 
 impl Deserializable for AccountStorage {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
-        // read complex types
-        let mut complex_types = BTreeMap::new();
-        let num_complex_types = source.read_u8()?;
-        for _ in 0..num_complex_types {
-            let idx = source.read_u8()?;
-            let slot_type: StorageSlotType =
-                source.read_u16()?.try_into().map_err(DeserializationError::InvalidValue)?;
-            complex_types.insert(idx, slot_type);
-        }
-
-        // read filled slots and build a vector of slot items
-        let mut items: Vec<SlotItem> = Vec::new();
-        let num_filled_slots = source.read_u8()?;
-        for _ in 0..num_filled_slots {
-            let idx = source.read_u8()?;
-            let slot_value: Word = source.read()?;
-            let slot_type = complex_types.remove(&idx).unwrap_or_default();
-            items.push((idx, (slot_type, slot_value)));
-        }
-
+        let items = Self::read_items(source)?;
         Self::new(items).map_err(|err| DeserializationError::InvalidValue(err.to_string()))
     }
 }
@@ -259,7 +670,10 @@ impl Deserializable for AccountStorage {
 
 #[cfg(test)]
 mod tests {
-    use super::{AccountStorage, Deserializable, Serializable, StorageSlotType};
+    use super::{
+        layout_commitment_of, AccountError, AccountStorage, AccountType, Deserializable, Digest,
+        Felt, Serializable, Smt, StorageLayoutBuilder, StorageSlotType,
+    };
     use crate::{ONE, ZERO};
 
     #[test]
@@ -292,4 +706,470 @@ mod tests {
         let bytes = storage.to_bytes();
         assert_eq!(storage, AccountStorage::read_from_bytes(&bytes).unwrap());
     }
+
+    #[test]
+    fn account_storage_deserialization_rejects_corrupted_layout() {
+        let storage = AccountStorage::new(vec![
+            (1, (StorageSlotType::Map { value_arity: 0 }, [ONE, ONE, ZERO, ZERO])),
+            (2, (StorageSlotType::Value { value_arity: 1 }, [ONE, ZERO, ZERO, ZERO])),
+        ])
+        .unwrap();
+        let bytes = storage.to_bytes();
+        assert_eq!(storage, AccountStorage::read_from_bytes(&bytes).unwrap());
+
+        // the complex types table is [num_types, (index, type)*] with 3 bytes per type
+        let map_type = u16::from(&StorageSlotType::Map { value_arity: 0 }).to_le_bytes();
+        let default_type = u16::from(&StorageSlotType::default()).to_le_bytes();
+        assert_eq!(&bytes[..4], &[2, 1, map_type[0], map_type[1]]);
+
+        let corrupt = |table: &[u8]| {
+            let mut corrupted = vec![2];
+            corrupted.extend_from_slice(table);
+            corrupted.extend_from_slice(&bytes[7..]);
+            corrupted
+        };
+
+        // duplicate slot index
+        let corrupted = corrupt(&[1, map_type[0], map_type[1], 1, map_type[0], map_type[1]]);
+        assert!(AccountStorage::read_from_bytes(&corrupted).is_err());
+        assert!(AccountStorage::read_lazy(&corrupted).is_err());
+
+        // reserved slot index
+        let corrupted = corrupt(&[1, map_type[0], map_type[1], 255, map_type[0], map_type[1]]);
+        assert!(AccountStorage::read_from_bytes(&corrupted).is_err());
+
+        // default slot type
+        let corrupted =
+            corrupt(&[1, map_type[0], map_type[1], 2, default_type[0], default_type[1]]);
+        assert!(AccountStorage::read_from_bytes(&corrupted).is_err());
+    }
+
+    #[test]
+    fn account_storage_deserialization_detects_missing_slot_type() {
+        let storage = AccountStorage::new(vec![(
+            1,
+            (StorageSlotType::Map { value_arity: 0 }, Smt::default().root().into()),
+        )])
+        .unwrap();
+        let bytes = storage.to_bytes();
+
+        // omit the type of the map slot from the complex types table; without the type the slot
+        // would otherwise be read as a value slot
+        let mut corrupted = vec![0];
+        corrupted.extend_from_slice(&bytes[4..]);
+        assert!(AccountStorage::read_from_bytes(&corrupted).is_err());
+        assert!(AccountStorage::read_lazy(&corrupted).is_err());
+
+        // a complex slot with an empty value keeps its type
+        let storage = AccountStorage::new(vec![(
+            2,
+            (StorageSlotType::Array { value_arity: 0, depth: 2 }, [ZERO; 4]),
+        )])
+        .unwrap();
+        let deserialized = AccountStorage::read_from_bytes(&storage.to_bytes()).unwrap();
+        assert_eq!(deserialized, storage);
+    }
+
+    #[test]
+    fn account_storage_serializes_empty_map_slot() {
+        // a map slot whose root equals the empty word
+        let storage = AccountStorage::new(vec![
+            (0, (StorageSlotType::Value { value_arity: 0 }, [ONE, ONE, ONE, ONE])),
+            (1, (StorageSlotType::Map { value_arity: 0 }, [ZERO; 4])),
+        ])
+        .unwrap();
+        let bytes = storage.to_bytes();
+
+        // the map slot is serialized as a filled slot even though its value is empty
+        assert_eq!(bytes[4], 2);
+
+        let deserialized = AccountStorage::read_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.layout()[1], StorageSlotType::Map { value_arity: 0 });
+        assert_eq!(deserialized.get_item_elements(1), [ZERO; 4]);
+        assert_eq!(deserialized, storage);
+
+        // omitting the value of the map slot is an error; each filled slot takes 33 bytes
+        let mut corrupted = bytes[..4].to_vec();
+        corrupted.push(1);
+        corrupted.extend_from_slice(&bytes[5..38]);
+        corrupted.extend_from_slice(&bytes[71..]);
+        assert!(AccountStorage::read_from_bytes(&corrupted).is_err());
+        assert!(AccountStorage::read_lazy(&corrupted).is_err());
+    }
+
+    #[test]
+    fn account_storage_snapshot_round_trip() {
+        let mut map = Smt::default();
+        map.insert(Digest::from([ONE, ZERO, ZERO, ZERO]), [ONE, ONE, ZERO, ZERO]);
+        let empty_map = Smt::default();
+
+        let storage = AccountStorage::new(vec![
+            (0, (StorageSlotType::Value { value_arity: 0 }, [ONE, ONE, ONE, ONE])),
+            (1, (StorageSlotType::Map { value_arity: 0 }, map.root().into())),
+            (2, (StorageSlotType::Map { value_arity: 0 }, empty_map.root().into())),
+            (3, (StorageSlotType::Value { value_arity: 1 }, [ZERO; 4])),
+        ])
+        .unwrap();
+
+        let snapshot = storage.snapshot();
+        assert_eq!(snapshot.len(), 4);
+
+        let restored = AccountStorage::from_snapshot(
+            snapshot.clone(),
+            vec![(1, map.clone()), (2, empty_map.clone())],
+        )
+        .unwrap();
+        assert_eq!(restored, storage);
+        assert_eq!(restored.root(), storage.root());
+        assert_eq!(restored.layout(), storage.layout());
+
+        // maps must match the values of their slots
+        assert_eq!(
+            AccountStorage::from_snapshot(snapshot.clone(), vec![(1, empty_map.clone())]),
+            Err(AccountError::StorageMapRootMismatch {
+                slot: 1,
+                expected: map.root(),
+                actual: empty_map.root(),
+            })
+        );
+
+        // maps can be provided only for map slots
+        assert_eq!(
+            AccountStorage::from_snapshot(snapshot, vec![(0, map)]),
+            Err(AccountError::StorageSlotNotMapSlot(
+                0,
+                StorageSlotType::Value { value_arity: 0 }
+            ))
+        );
+    }
+
+    #[test]
+    fn account_storage_read_lazy() {
+        let storage = AccountStorage::new(vec![
+            (0, (StorageSlotType::Value { value_arity: 0 }, [ONE, ONE, ONE, ONE])),
+            (2, (StorageSlotType::Map { value_arity: 2 }, [ONE, ONE, ZERO, ZERO])),
+            (
+                3,
+                (StorageSlotType::Array { depth: 4, value_arity: 3 }, [ONE, ZERO, ZERO, ZERO]),
+            ),
+        ])
+        .unwrap();
+        let bytes = storage.to_bytes();
+
+        // slot values and layout can be read without building the storage tree
+        let lazy_storage = AccountStorage::read_lazy(&bytes).unwrap();
+        for index in [0, 1, 2, 3, AccountStorage::SLOT_LAYOUT_COMMITMENT_INDEX] {
+            assert_eq!(lazy_storage.get_item(index), storage.get_item(index));
+        }
+        assert_eq!(lazy_storage.layout(), storage.layout());
+        assert!(!lazy_storage.is_built());
+
+        // computing the root builds the tree
+        assert_eq!(lazy_storage.root(), storage.root());
+        assert!(lazy_storage.is_built());
+        assert_eq!(lazy_storage.storage(), &AccountStorage::read_from_bytes(&bytes).unwrap());
+
+        // lazy storage can be converted without computing the root first
+        let lazy_storage = AccountStorage::read_lazy(&bytes).unwrap();
+        assert_eq!(lazy_storage.into_storage(), storage);
+    }
+
+    #[test]
+    fn account_storage_free_slots() {
+        let max_slot_index = AccountStorage::max_slot_index();
+        assert_eq!(max_slot_index, 254);
+
+        let storage = AccountStorage::new(Vec::new()).unwrap();
+        assert_eq!(storage.free_slots(), (0..=max_slot_index).collect::<Vec<_>>());
+
+        // fill all slots except for slot 7 and the last one; slot 9 has a non-default type but
+        // an empty value
+        let items = (0..max_slot_index)
+            .filter(|&index| index != 7)
+            .map(|index| {
+                if index == 9 {
+                    (index, (StorageSlotType::Map { value_arity: 0 }, [ZERO; 4]))
+                } else {
+                    (index, (StorageSlotType::default(), [ONE, ZERO, ZERO, ZERO]))
+                }
+            })
+            .collect();
+        let mut storage = AccountStorage::new(items).unwrap();
+        assert_eq!(storage.free_slots(), vec![7, max_slot_index]);
+
+        storage.set_item(max_slot_index, [ONE; 4]).unwrap();
+        assert_eq!(storage.free_slots(), vec![7]);
+
+        // reserved slots cannot be used
+        assert_eq!(
+            storage.set_item(AccountStorage::SLOT_LAYOUT_COMMITMENT_INDEX, [ONE; 4]),
+            Err(AccountError::StorageSlotIsReserved(
+                AccountStorage::SLOT_LAYOUT_COMMITMENT_INDEX
+            ))
+        );
+    }
+
+    #[test]
+    fn account_storage_occupied_slots() {
+        let storage = AccountStorage::new(Vec::new()).unwrap();
+        assert!(storage.is_empty());
+        assert_eq!(storage.occupied_slots(), 0);
+
+        // slot 4 has a non-default type but an empty value, so it is not counted
+        let mut storage = AccountStorage::new(vec![
+            (0, (StorageSlotType::default(), [ONE, ONE, ONE, ONE])),
+            (2, (StorageSlotType::default(), [ONE, ZERO, ZERO, ZERO])),
+            (4, (StorageSlotType::Map { value_arity: 0 }, [ZERO; 4])),
+        ])
+        .unwrap();
+        assert!(!storage.is_empty());
+        assert_eq!(storage.occupied_slots(), 2);
+
+        storage.set_item(0, [ZERO; 4]).unwrap();
+        assert_eq!(storage.occupied_slots(), 1);
+    }
+
+    #[test]
+    fn account_storage_commitment_size_in_felts() {
+        let storage = AccountStorage::new(Vec::new()).unwrap();
+        assert_eq!(storage.commitment_size_in_felts(), 0);
+
+        // two populated value slots, one populated map slot and one empty map slot
+        let storage = AccountStorage::new(vec![
+            (0, (StorageSlotType::default(), [ONE, ONE, ONE, ONE])),
+            (1, (StorageSlotType::default(), [ZERO; 4])),
+            (3, (StorageSlotType::default(), [ONE, ZERO, ZERO, ZERO])),
+            (5, (StorageSlotType::Map { value_arity: 0 }, [ONE, ONE, ZERO, ZERO])),
+            (6, (StorageSlotType::Map { value_arity: 2 }, [ZERO; 4])),
+        ])
+        .unwrap();
+        assert_eq!(storage.commitment_size_in_felts(), 3 * 4);
+    }
+
+    #[test]
+    fn account_storage_mutable_slots() {
+        let mut storage = AccountStorage::new(vec![
+            (0, (StorageSlotType::default(), [ONE, ONE, ONE, ONE])),
+            (1, (StorageSlotType::Value { value_arity: 1 }, [ONE, ZERO, ZERO, ZERO])),
+            (2, (StorageSlotType::Map { value_arity: 0 }, [ONE, ONE, ZERO, ZERO])),
+        ])
+        .unwrap();
+
+        // value slots of arity 0 are mutable, including unpopulated ones
+        assert!(storage.is_mutable_slot(0));
+        assert!(storage.is_mutable_slot(3));
+        assert!(storage.set_item(0, [ZERO; 4]).is_ok());
+
+        // value slots of higher arity and map slots are not mutable
+        assert!(!storage.is_mutable_slot(1));
+        assert!(storage.set_item(1, [ZERO; 4]).is_err());
+        assert!(!storage.is_mutable_slot(2));
+        assert!(storage.set_item(2, [ZERO; 4]).is_err());
+
+        // the reserved slot is not mutable
+        let reserved_slot = AccountStorage::SLOT_LAYOUT_COMMITMENT_INDEX;
+        assert!(!storage.is_mutable_slot(reserved_slot));
+        assert!(storage.set_item(reserved_slot, [ZERO; 4]).is_err());
+    }
+
+    #[test]
+    fn account_storage_item_elements() {
+        let value = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let mut storage = AccountStorage::new(Vec::new()).unwrap();
+        assert_eq!(storage.get_item_elements(5), [ZERO; 4]);
+
+        storage.set_item(5, value).unwrap();
+        assert_eq!(storage.get_item_elements(5), value);
+        assert_eq!(storage.get_item(5).as_elements(), &value);
+    }
+
+    #[test]
+    fn account_storage_get_items() {
+        let value = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let storage = AccountStorage::new(vec![(3, (StorageSlotType::default(), value))]).unwrap();
+
+        let items = storage.get_items(&[3, 0, AccountStorage::SLOT_LAYOUT_COMMITMENT_INDEX, 3]);
+        assert_eq!(items, vec![value, [ZERO; 4], storage.layout_commitment().into(), value]);
+        assert!(storage.get_items(&[]).is_empty());
+    }
+
+    #[test]
+    fn account_storage_all_slots() {
+        let items = (0..=AccountStorage::max_slot_index())
+            .map(|index| (index, (StorageSlotType::default(), [Felt::from(index), ONE, ONE, ONE])))
+            .collect();
+        let mut storage = AccountStorage::new(items).unwrap();
+
+        for index in 0..=AccountStorage::max_slot_index() {
+            assert_eq!(storage.get_item_elements(index), [Felt::from(index), ONE, ONE, ONE]);
+            storage.set_item(index, [ZERO; 4]).unwrap();
+        }
+        assert_eq!(storage.free_slots().len(), AccountStorage::NUM_STORAGE_SLOTS - 1);
+    }
+
+    #[test]
+    fn account_storage_with_template() {
+        let faucet_slot = AccountStorage::FAUCET_DATA_SLOT_INDEX;
+        let value = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let user_slots = vec![(0, (StorageSlotType::default(), value))];
+
+        // fungible faucets store their data in a value slot
+        let storage =
+            AccountStorage::with_template(AccountType::FungibleFaucet, user_slots.clone()).unwrap();
+        assert_eq!(
+            storage.layout()[faucet_slot as usize],
+            StorageSlotType::Value { value_arity: 0 }
+        );
+        assert_eq!(storage.get_item_elements(0), value);
+
+        // non-fungible faucets store their data in a map slot
+        let storage =
+            AccountStorage::with_template(AccountType::NonFungibleFaucet, user_slots.clone())
+                .unwrap();
+        assert_eq!(storage.layout()[faucet_slot as usize], StorageSlotType::Map { value_arity: 0 });
+        assert_eq!(storage.get_item(faucet_slot), Smt::default().root());
+
+        // regular accounts do not reserve the faucet slot
+        let storage = AccountStorage::with_template(
+            AccountType::RegularAccountUpdatableCode,
+            user_slots.clone(),
+        )
+        .unwrap();
+        assert_eq!(storage, AccountStorage::new(user_slots).unwrap());
+
+        let faucet_slot_item = vec![(faucet_slot, (StorageSlotType::default(), value))];
+        assert!(AccountStorage::with_template(
+            AccountType::RegularAccountImmutableCode,
+            faucet_slot_item.clone()
+        )
+        .is_ok());
+
+        // faucets cannot use the faucet slot for user data
+        assert_eq!(
+            AccountStorage::with_template(AccountType::FungibleFaucet, faucet_slot_item),
+            Err(AccountError::StorageSlotIsReserved(faucet_slot))
+        );
+    }
+
+    #[test]
+    fn storage_layout_builder_allocates_slots() {
+        let mut builder = StorageLayoutBuilder::new();
+        let value_type = StorageSlotType::Value { value_arity: 0 };
+        let map_type = StorageSlotType::Map { value_arity: 0 };
+        let array_type = StorageSlotType::Array { depth: 4, value_arity: 1 };
+
+        assert_eq!(builder.allocate(value_type), Ok(0));
+        assert_eq!(builder.allocate(map_type), Ok(1));
+        assert_eq!(builder.allocate_with_value(value_type, [ONE, ONE, ONE, ONE]), Ok(2));
+        assert_eq!(builder.allocate(array_type), Ok(3));
+        assert_eq!(builder.num_allocated(), 4);
+        assert_eq!(builder.slot_type(1), Some(map_type));
+        assert_eq!(builder.slot_type(4), None);
+
+        let storage = AccountStorage::new(builder.build()).unwrap();
+        assert_eq!(&storage.layout()[..4], &[value_type, map_type, value_type, array_type]);
+        assert_eq!(storage.get_item(1), Smt::default().root());
+        assert_eq!(storage.get_item_elements(2), [ONE, ONE, ONE, ONE]);
+    }
+
+    #[test]
+    fn storage_layout_builder_never_allocates_reserved_slots() {
+        let mut builder = StorageLayoutBuilder::new();
+        for index in 0..=AccountStorage::max_slot_index() {
+            assert_eq!(builder.allocate(StorageSlotType::default()), Ok(index));
+        }
+        assert_eq!(
+            builder.allocate(StorageSlotType::default()),
+            Err(AccountError::StorageSlotsExhausted)
+        );
+
+        assert!(AccountStorage::new(builder.build()).is_ok());
+    }
+
+    #[test]
+    fn account_storage_layout_commitment_is_pinned() {
+        let storage = AccountStorage::new(vec![]).unwrap();
+        assert_eq!(layout_commitment_of(storage.layout()), storage.layout_commitment());
+
+        // the commitment of the default layout must not change unless the hash function is
+        // changed on purpose
+        assert_eq!(
+            storage.layout_commitment().to_hex(),
+            "0xdca41bb7d8ca92c9bf01f71be69ba5291e85ae894d7181813617ec5c19d90c6e"
+        );
+    }
+
+    #[test]
+    fn account_storage_set_items_is_atomic() {
+        let storage = AccountStorage::new(vec![
+            (0, (StorageSlotType::Value { value_arity: 0 }, [ONE, ZERO, ZERO, ZERO])),
+            (1, (StorageSlotType::Value { value_arity: 0 }, [ZERO; 4])),
+        ])
+        .unwrap();
+
+        // a batch containing a write to a reserved slot leaves all slots unchanged
+        let mut updated = storage.clone();
+        let items = [
+            (0, [ONE; 4]),
+            (1, [ONE; 4]),
+            (AccountStorage::SLOT_LAYOUT_COMMITMENT_INDEX, [ONE; 4]),
+        ];
+        assert!(matches!(
+            updated.set_items(&items),
+            Err(AccountError::StorageSlotIsReserved(
+                AccountStorage::SLOT_LAYOUT_COMMITMENT_INDEX
+            ))
+        ));
+        assert_eq!(updated, storage);
+
+        // a valid batch updates all slots
+        updated.set_items(&items[..2]).unwrap();
+        assert_eq!(updated.get_item(0), Digest::from([ONE; 4]));
+        assert_eq!(updated.get_item(1), Digest::from([ONE; 4]));
+    }
+
+    #[cfg(feature = "debug-log")]
+    #[test]
+    fn account_storage_mutation_log() {
+        use super::{AccountStorageDelta, StorageMutation, Word};
+
+        let mut storage = AccountStorage::new(vec![
+            (0, (StorageSlotType::default(), [ONE, ONE, ONE, ONE])),
+            (2, (StorageSlotType::default(), [ONE, ONE, ONE, ZERO])),
+        ])
+        .unwrap();
+        assert!(storage.mutation_log().is_empty());
+
+        let new_value = [ONE, ZERO, ONE, ZERO];
+        let delta = AccountStorageDelta {
+            cleared_items: vec![0],
+            updated_items: vec![(2, new_value), (1, new_value)],
+        };
+        storage.apply_delta(&delta).unwrap();
+
+        let expected = vec![
+            StorageMutation {
+                index: 0,
+                old_value: [ONE, ONE, ONE, ONE],
+                new_value: Word::default(),
+            },
+            StorageMutation {
+                index: 2,
+                old_value: [ONE, ONE, ONE, ZERO],
+                new_value,
+            },
+            StorageMutation {
+                index: 1,
+                old_value: Word::default(),
+                new_value,
+            },
+        ];
+        assert_eq!(storage.mutation_log(), expected.as_slice());
+
+        // the log does not affect storage equality
+        let mut other = storage.clone();
+        other.set_item(1, new_value).unwrap();
+        assert_eq!(other.mutation_log().len(), 4);
+        assert_eq!(storage, other);
+    }
 }
@@ -1,4 +1,4 @@
-use super::Felt;
+use super::{AccountError, Felt};
 use crate::utils::string::*;
 
 // CONSTANTS
@@ -28,6 +28,59 @@ pub enum StorageSlotType {
 }
 
 impl StorageSlotType {
+    /// Returns the kind of this storage slot type.
+    pub fn kind(&self) -> StorageSlotKind {
+        match self {
+            StorageSlotType::Value { .. } => StorageSlotKind::Value,
+            StorageSlotType::Map { .. } => StorageSlotKind::Map,
+            StorageSlotType::Array { .. } => StorageSlotKind::Array,
+        }
+    }
+
+    /// Returns true if this storage slot type is a value type of any arity.
+    pub fn is_value(&self) -> bool {
+        self.kind() == StorageSlotKind::Value
+    }
+
+    /// Returns true if this storage slot type is a map type.
+    pub fn is_map(&self) -> bool {
+        self.kind() == StorageSlotKind::Map
+    }
+
+    /// Returns true if this storage slot type is an array type.
+    pub fn is_array(&self) -> bool {
+        self.kind() == StorageSlotKind::Array
+    }
+
+    /// Returns an array slot type with the smallest depth which can hold `max_elements` values of
+    /// the specified arity.
+    ///
+    /// The depth is computed as the smallest `depth` such that 2^depth >= max_elements, but it is
+    /// never smaller than the minimum array depth of 2.
+    ///
+    /// # Errors
+    /// Returns an error if the resulting slot type is not valid (i.e., the value arity is too
+    /// large).
+    pub fn array_for_capacity(
+        max_elements: u64,
+        value_arity: u8,
+    ) -> Result<StorageSlotType, AccountError> {
+        let depth = match max_elements {
+            0 | 1 => 0,
+            n => (u64::BITS - (n - 1).leading_zeros()) as u8,
+        };
+        let slot_type = StorageSlotType::Array {
+            depth: depth.max(MIN_ARRAY_DEPTH),
+            value_arity,
+        };
+
+        if !slot_type.is_valid() {
+            return Err(AccountError::StorageSlotInvalidType(slot_type));
+        }
+
+        Ok(slot_type)
+    }
+
     /// Returns true if this storage slot type is valid.
     ///
     /// Valid storage slot types are defined as follows:
@@ -60,6 +113,18 @@ impl Default for StorageSlotType {
     }
 }
 
+// STORAGE SLOT KIND
+// ================================================================================================
+
+/// The kind of a storage slot, i.e., a [StorageSlotType] without its associated data.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum StorageSlotKind {
+    Value,
+    Map,
+    Array,
+}
+
 // CONVERSIONS INTO STORAGE SLOT TYPE
 // ================================================================================================
 
@@ -138,3 +203,54 @@ impl From<&StorageSlotType> for Felt {
         Self::from(*value)
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        StorageSlotKind, StorageSlotType, MAX_ARRAY_DEPTH, MAX_VALUE_ARITY, MIN_ARRAY_DEPTH,
+    };
+
+    #[test]
+    fn slot_type_predicates() {
+        let value = StorageSlotType::Value { value_arity: 1 };
+        assert_eq!(value.kind(), StorageSlotKind::Value);
+        assert!(value.is_value() && !value.is_map() && !value.is_array());
+        assert!(StorageSlotType::default().is_value());
+
+        let map = StorageSlotType::Map { value_arity: 0 };
+        assert_eq!(map.kind(), StorageSlotKind::Map);
+        assert!(!map.is_value() && map.is_map() && !map.is_array());
+
+        let array = StorageSlotType::Array { depth: 4, value_arity: 0 };
+        assert_eq!(array.kind(), StorageSlotKind::Array);
+        assert!(!array.is_value() && !array.is_map() && array.is_array());
+    }
+
+    #[test]
+    fn array_slot_type_for_capacity() {
+        let array = |depth| StorageSlotType::Array { depth, value_arity: 1 };
+
+        // small capacities use the minimum depth
+        for capacity in 0..=4 {
+            let slot_type = StorageSlotType::array_for_capacity(capacity, 1).unwrap();
+            assert_eq!(slot_type, array(MIN_ARRAY_DEPTH));
+        }
+
+        // exactly a power of two and one over
+        assert_eq!(StorageSlotType::array_for_capacity(1 << 10, 1).unwrap(), array(10));
+        assert_eq!(StorageSlotType::array_for_capacity((1 << 10) + 1, 1).unwrap(), array(11));
+
+        // the largest capacities
+        assert_eq!(StorageSlotType::array_for_capacity(1 << 63, 1).unwrap(), array(63));
+        assert_eq!(
+            StorageSlotType::array_for_capacity(u64::MAX, 1).unwrap(),
+            array(MAX_ARRAY_DEPTH)
+        );
+
+        // invalid value arity
+        assert!(StorageSlotType::array_for_capacity(8, MAX_VALUE_ARITY).is_err());
+    }
+}
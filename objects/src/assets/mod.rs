@@ -100,6 +100,32 @@ impl Asset {
         matches!(self, Self::Fungible(_))
     }
 
+    /// Returns ID of the faucet which issued this asset.
+    pub fn faucet_id(&self) -> AccountId {
+        match self {
+            Self::Fungible(asset) => asset.faucet_id(),
+            Self::NonFungible(asset) => asset.faucet_id(),
+        }
+    }
+
+    /// Returns a reference to the underlying fungible asset, or None if this asset is a
+    /// non-fungible asset.
+    pub fn as_fungible(&self) -> Option<&FungibleAsset> {
+        match self {
+            Self::Fungible(asset) => Some(asset),
+            Self::NonFungible(_) => None,
+        }
+    }
+
+    /// Returns a reference to the underlying non-fungible asset, or None if this asset is a
+    /// fungible asset.
+    pub fn as_non_fungible(&self) -> Option<&NonFungibleAsset> {
+        match self {
+            Self::Fungible(_) => None,
+            Self::NonFungible(asset) => Some(asset),
+        }
+    }
+
     /// Returns the key which is used to store this asset in the account vault.
     pub fn vault_key(&self) -> Word {
         match self {
@@ -211,3 +237,45 @@ fn parse_word(bytes: [u8; 32]) -> Result<Word, AssetError> {
 fn parse_felt(bytes: &[u8]) -> Result<Felt, AssetError> {
     Felt::try_from(bytes).map_err(|err| AssetError::invalid_field_element(err.to_string()))
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{AccountId, Asset, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails};
+    use crate::accounts::{
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN,
+    };
+
+    #[test]
+    fn asset_accessors() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let fungible_asset = FungibleAsset::new(faucet_id, 100).unwrap();
+        let asset = Asset::Fungible(fungible_asset);
+        assert!(asset.is_fungible());
+        assert_eq!(asset.faucet_id(), faucet_id);
+        assert_eq!(asset.as_fungible(), Some(&fungible_asset));
+
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+        let details = NonFungibleAssetDetails::new(faucet_id, vec![1, 2, 3]).unwrap();
+        let asset = Asset::NonFungible(NonFungibleAsset::new(&details).unwrap());
+        assert!(!asset.is_fungible());
+        assert_eq!(asset.faucet_id(), faucet_id);
+        assert_eq!(asset.as_fungible(), None);
+    }
+
+    #[test]
+    fn non_fungible_asset_details_verify() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+        let details = NonFungibleAssetDetails::new(faucet_id, vec![1, 2, 3]).unwrap();
+        let asset = details.to_asset();
+        assert_eq!(asset, NonFungibleAsset::new(&details).unwrap());
+        assert!(details.verify(&asset));
+
+        // details with tampered data do not match the asset
+        let tampered_details = NonFungibleAssetDetails::new(faucet_id, vec![1, 2, 4]).unwrap();
+        assert!(!tampered_details.verify(&asset));
+        assert!(!details.verify(&tampered_details.to_asset()));
+    }
+}
@@ -187,4 +187,16 @@ impl NonFungibleAssetDetails {
     pub fn asset_data(&self) -> &[u8] {
         &self.asset_data
     }
+
+    /// Returns the non-fungible asset which commits to these asset details.
+    pub fn to_asset(&self) -> NonFungibleAsset {
+        NonFungibleAsset::new(self).expect("faucet ID was validated on construction")
+    }
+
+    /// Returns true if the provided non-fungible asset commits to these asset details, i.e., if
+    /// the asset was issued by the faucet of these details and its data hash was computed from
+    /// the asset data of these details.
+    pub fn verify(&self, asset: &NonFungibleAsset) -> bool {
+        self.to_asset() == *asset
+    }
 }
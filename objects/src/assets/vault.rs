@@ -5,7 +5,7 @@ use super::{
 use crate::{
     crypto::merkle::Smt,
     utils::{collections::*, string::*},
-    AssetVaultError, Digest,
+    AssetError, AssetVaultError, Digest,
 };
 
 // ASSET VAULT
@@ -40,6 +40,38 @@ impl AssetVault {
         })
     }
 
+    /// Returns a new [AssetVault] holding the specified balances of fungible assets and the
+    /// specified non-fungible assets.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Any of the balances is zero or is not a valid fungible asset amount.
+    /// - Any of the balance keys is not an ID of a fungible faucet.
+    /// - The same non-fungible asset is provided more than once.
+    pub fn from_balances(
+        balances: BTreeMap<AccountId, u64>,
+        nfts: Vec<NonFungibleAsset>,
+    ) -> Result<Self, AssetError> {
+        let mut assets = Vec::with_capacity(balances.len() + nfts.len());
+        for (faucet_id, amount) in balances {
+            if amount == 0 {
+                return Err(AssetError::FungibleAssetAmountZero(faucet_id));
+            }
+            assets.push(FungibleAsset::new(faucet_id, amount)?.into());
+        }
+
+        let mut nft_keys = BTreeSet::new();
+        for nft in nfts {
+            if !nft_keys.insert(Digest::from(nft.vault_key())) {
+                return Err(AssetError::DuplicateNonFungibleAsset(nft));
+            }
+            assets.push(nft.into());
+        }
+
+        // fungible and non-fungible assets never share a vault key and all keys are unique
+        Ok(Self::new(&assets).expect("assets have unique vault keys"))
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -83,6 +115,20 @@ impl AssetVault {
         self.asset_tree.entries().map(|x| Asset::new_unchecked(x.1))
     }
 
+    /// Returns all fungible and non-fungible assets in this vault which were issued by the
+    /// specified faucet.
+    ///
+    /// For a fungible faucet, the returned vector contains at most one asset holding the entire
+    /// balance of the faucet's asset.
+    pub fn assets_from_faucet(&self, faucet_id: AccountId) -> Vec<Asset> {
+        self.assets().filter(|asset| asset.faucet_id() == faucet_id).collect()
+    }
+
+    /// Returns the IDs of all faucets which issued at least one asset stored in this vault.
+    pub fn faucet_ids(&self) -> BTreeSet<AccountId> {
+        self.assets().map(|asset| asset.faucet_id()).collect()
+    }
+
     /// Returns a reference to the Sparse Merkle Tree underling this asset vault.
     pub fn asset_tree(&self) -> &Smt {
         &self.asset_tree
@@ -239,3 +285,97 @@ impl Deserializable for AssetVault {
         Self::new(&assets).map_err(|err| DeserializationError::InvalidValue(err.to_string()))
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{AccountId, Asset, AssetError, AssetVault, FungibleAsset, NonFungibleAsset};
+    use crate::{
+        accounts::{
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
+            ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+        },
+        assets::NonFungibleAssetDetails,
+        utils::collections::*,
+    };
+
+    #[test]
+    fn asset_vault_assets_from_faucet() {
+        let faucet_id_1 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let faucet_id_2 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2).unwrap();
+        let nft_faucet_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+
+        let fungible_1: Asset = FungibleAsset::new(faucet_id_1, 100).unwrap().into();
+        let fungible_2: Asset = FungibleAsset::new(faucet_id_2, 200).unwrap().into();
+        let nft_1: Asset = NonFungibleAsset::new(
+            &NonFungibleAssetDetails::new(nft_faucet_id, vec![1, 2, 3]).unwrap(),
+        )
+        .unwrap()
+        .into();
+        let nft_2: Asset = NonFungibleAsset::new(
+            &NonFungibleAssetDetails::new(nft_faucet_id, vec![4, 5, 6]).unwrap(),
+        )
+        .unwrap()
+        .into();
+
+        let vault = AssetVault::new(&[fungible_1, nft_1, fungible_2, nft_2]).unwrap();
+
+        assert_eq!(vault.assets_from_faucet(faucet_id_1), vec![fungible_1]);
+        assert_eq!(vault.assets_from_faucet(faucet_id_2), vec![fungible_2]);
+
+        let mut nfts = vault.assets_from_faucet(nft_faucet_id);
+        assert_eq!(nfts.len(), 2);
+        nfts.retain(|asset| *asset != nft_1 && *asset != nft_2);
+        assert!(nfts.is_empty());
+
+        // accounts which are not faucets did not issue any assets
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        assert!(vault.assets_from_faucet(account_id).is_empty());
+    }
+
+    #[test]
+    fn asset_vault_from_balances() {
+        let faucet_id_1 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let faucet_id_2 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2).unwrap();
+        let nft_faucet_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+        let nft = NonFungibleAsset::new(
+            &NonFungibleAssetDetails::new(nft_faucet_id, vec![1, 2, 3]).unwrap(),
+        )
+        .unwrap();
+
+        let balances = BTreeMap::from([(faucet_id_1, 100), (faucet_id_2, 200)]);
+        let vault = AssetVault::from_balances(balances.clone(), vec![nft]).unwrap();
+
+        let expected = AssetVault::new(&[
+            FungibleAsset::new(faucet_id_1, 100).unwrap().into(),
+            FungibleAsset::new(faucet_id_2, 200).unwrap().into(),
+            nft.into(),
+        ])
+        .unwrap();
+        assert_eq!(vault.commitment(), expected.commitment());
+        assert_eq!(vault.get_balance(faucet_id_1).unwrap(), 100);
+        assert_eq!(vault.get_balance(faucet_id_2).unwrap(), 200);
+        assert!(vault.has_non_fungible_asset(nft.into()).unwrap());
+
+        // zero balances are rejected
+        let zero_balances = BTreeMap::from([(faucet_id_1, 0)]);
+        assert!(matches!(
+            AssetVault::from_balances(zero_balances, vec![]),
+            Err(AssetError::FungibleAssetAmountZero(id)) if id == faucet_id_1
+        ));
+
+        // balances can only be specified for fungible faucets
+        let nft_balances = BTreeMap::from([(nft_faucet_id, 1)]);
+        assert!(AssetVault::from_balances(nft_balances, vec![]).is_err());
+
+        // duplicate non-fungible assets are rejected
+        assert!(matches!(
+            AssetVault::from_balances(balances, vec![nft, nft]),
+            Err(AssetError::DuplicateNonFungibleAsset(asset)) if asset == nft
+        ));
+    }
+}
@@ -7,7 +7,7 @@ use super::{
     accounts::{AccountId, StorageSlotType},
     assets::{Asset, FungibleAsset, NonFungibleAsset},
     crypto::merkle::MerkleError,
-    notes::NoteId,
+    notes::{InputFieldKind, NoteId},
     utils::string::*,
     Digest, Word,
 };
@@ -19,20 +19,61 @@ use super::{
 pub enum AccountError {
     AccountCodeAssemblerError(AssemblyError),
     AccountCodeNoProcedures,
-    AccountCodeTooManyProcedures { max: usize, actual: usize },
+    AccountCodeTooManyProcedures {
+        max: usize,
+        actual: usize,
+    },
+    AccountDeltaInvalid(AccountDeltaError),
     AccountIdInvalidFieldElement(String),
+    AccountIdInvalidRest(u64),
     AccountIdTooFewOnes,
+    AccountTypeInvalid(String),
     AssetVaultUpdateError(AssetVaultError),
     DuplicateStorageItems(MerkleError),
+    FundingNoteInvalid(String),
     FungibleFaucetIdInvalidFirstBit,
     FungibleFaucetInvalidMetadata(String),
     HexParseError(String),
-    InconsistentAccountIdSeed { expected: AccountId, actual: AccountId },
-    NonceNotMonotonicallyIncreasing { current: u64, new: u64 },
-    SeedDigestTooFewTrailingZeros { expected: u32, actual: u32 },
-    StorageSlotInvalidValueArity { slot: u8, expected: u8, actual: u8 },
+    InconsistentAccountId {
+        expected: AccountId,
+        actual: AccountId,
+    },
+    InconsistentAccountIdSeed {
+        expected: AccountId,
+        actual: AccountId,
+    },
+    InvalidAuthSchemeCount {
+        max: usize,
+        actual: usize,
+    },
+    InvalidNonceIncrement {
+        current: u64,
+        increment: u64,
+    },
+    NonceNotMonotonicallyIncreasing {
+        current: u64,
+        new: u64,
+    },
+    SeedDigestTooFewTrailingZeros {
+        expected: u32,
+        actual: u32,
+    },
+    StorageMapRootMismatch {
+        slot: u8,
+        expected: Digest,
+        actual: Digest,
+    },
+    StorageSlotIndexOutOfBounds(u8),
+    StorageSlotInvalidType(StorageSlotType),
+    StorageSlotInvalidValueArity {
+        slot: u8,
+        expected: u8,
+        actual: u8,
+    },
     StorageSlotIsReserved(u8),
+    StorageSlotNotMapSlot(u8, StorageSlotType),
     StorageSlotNotValueSlot(u8, StorageSlotType),
+    StorageSlotsExhausted,
     StubDataIncorrectLength(usize, usize),
 }
 
@@ -94,6 +135,8 @@ impl fmt::Display for AccountDeltaError {
 pub enum AssetError {
     AmountTooBig(u64),
     AssetAmountNotSufficient(u64, u64),
+    DuplicateNonFungibleAsset(NonFungibleAsset),
+    FungibleAssetAmountZero(AccountId),
     FungibleAssetInvalidFirstBit,
     FungibleAssetInvalidTag(u32),
     FungibleAssetInvalidWord(Word),
@@ -102,6 +145,7 @@ pub enum AssetError {
     InvalidFieldElement(String),
     NonFungibleAssetInvalidFirstBit,
     NonFungibleAssetInvalidTag(u32),
+    NonFungibleAssetNotAvailable(NonFungibleAsset),
     NotAFungibleFaucetId(AccountId),
     NotANonFungibleFaucetId(AccountId),
     NotAnAsset(Word),
@@ -117,6 +161,10 @@ impl AssetError {
         Self::AssetAmountNotSufficient(available, requested)
     }
 
+    pub fn duplicate_non_fungible_asset(asset: NonFungibleAsset) -> Self {
+        Self::DuplicateNonFungibleAsset(asset)
+    }
+
     pub fn fungible_asset_invalid_first_bit() -> Self {
         Self::FungibleAssetInvalidFirstBit
     }
@@ -149,6 +197,10 @@ impl AssetError {
         Self::NonFungibleAssetInvalidTag(tag)
     }
 
+    pub fn non_fungible_asset_not_available(asset: NonFungibleAsset) -> Self {
+        Self::NonFungibleAssetNotAvailable(asset)
+    }
+
     pub fn not_a_fungible_faucet_id(id: AccountId) -> Self {
         Self::NotAFungibleFaucetId(id)
     }
@@ -200,16 +252,23 @@ impl std::error::Error for AssetVaultError {}
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum NoteError {
+    ClaimAmountTooLarge { claim_amount: u64, amount: u64 },
     DuplicateFungibleAsset(AccountId),
     DuplicateNonFungibleAsset(NonFungibleAsset),
     EmptyAssetList,
     InconsistentStubId(NoteId, NoteId),
     InconsistentStubAssetHash(Digest, Digest),
+    InputSchemaLengthMismatch { expected: usize, actual: usize },
     InvalidStubDataLen(usize),
     InvalidOriginIndex(String),
     InvalidAssetData(AssetError),
+    InvalidAssetFaucet(AccountId),
+    InvalidInputFieldKind(u8),
+    InvalidNoteInput { index: usize, kind: InputFieldKind },
+    InvalidNoteTag(u64),
     NoteDeserializationError(DeserializationError),
     NoteMetadataSenderInvalid(AccountError),
+    NoteSigningFailed(String),
     ScriptCompilationError(AssemblyError),
     TooManyAssets(usize),
     TooManyInputs(usize),
@@ -311,6 +370,7 @@ pub enum TransactionInputError {
     InconsistentChainLength { expected: u32, actual: u32 },
     InconsistentChainRoot { expected: Digest, actual: Digest },
     InputNoteBlockNotInChainMmr(NoteId),
+    InputNoteMissingProof(NoteId),
     InputNoteNotInBlock(NoteId, u32),
     InvalidAccountSeed(AccountError),
     TooManyInputNotes { max: usize, actual: usize },
@@ -1,6 +1,5 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-#[cfg(not(feature = "std"))]
 extern crate alloc;
 
 pub mod accounts;
@@ -8,6 +7,7 @@ pub mod assets;
 pub mod block;
 pub mod notes;
 pub mod transaction;
+pub mod vm;
 
 mod constants;
 mod errors;
@@ -28,7 +28,7 @@ pub mod assembly {
     pub use assembly::{
         ast::{AstSerdeOptions, ModuleAst, ProgramAst},
         Assembler, AssemblyContext, AssemblyError, Library, LibraryNamespace, LibraryPath,
-        MaslLibrary, Version,
+        MaslLibrary, Module, Version,
     };
 }
 
@@ -40,15 +40,12 @@ pub mod utils {
     pub use miden_crypto::utils::{bytes_to_hex_string, format, hex_to_bytes, vec, HexParseError};
     pub use vm_core::utils::*;
 
+    mod word;
+    pub use word::{word_from_u64s, word_to_u64s};
+
     pub mod serde {
         pub use miden_crypto::utils::{
             ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
         };
     }
 }
-
-pub mod vm {
-    pub use miden_verifier::ExecutionProof;
-    pub use vm_core::{code_blocks::CodeBlock, Program, ProgramInfo};
-    pub use vm_processor::{AdviceInputs, AdviceMap, StackInputs, StackOutputs};
-}
@@ -3,6 +3,7 @@ use super::{
     NoteError, Serializable, Word, WORD_SIZE, ZERO,
 };
 use crate::{
+    accounts::AccountType,
     utils::{collections::*, format},
     MAX_ASSETS_PER_NOTE,
 };
@@ -38,6 +39,7 @@ impl NoteAssets {
     /// - The asset list is empty.
     /// - The list contains more than 256 assets.
     /// - There are duplicate assets in the list.
+    /// - An asset was not issued by a faucet of the matching type.
     pub fn new(assets: &[Asset]) -> Result<Self, NoteError> {
         if assets.is_empty() {
             return Err(NoteError::EmptyAssetList);
@@ -45,6 +47,17 @@ impl NoteAssets {
             return Err(NoteError::too_many_assets(assets.len()));
         }
 
+        // make sure all assets were issued by a faucet of the matching type
+        for asset in assets {
+            let (faucet_id, faucet_type) = match asset {
+                Asset::Fungible(a) => (a.faucet_id(), AccountType::FungibleFaucet),
+                Asset::NonFungible(a) => (a.faucet_id(), AccountType::NonFungibleFaucet),
+            };
+            if faucet_id.account_type() != faucet_type {
+                return Err(NoteError::InvalidAssetFaucet(faucet_id));
+            }
+        }
+
         // make sure all provided assets are unique
         for (i, asset) in assets.iter().enumerate() {
             // for all assets except the last one, check if the asset is the same as any other
@@ -114,6 +127,77 @@ impl PartialEq for NoteAssets {
 
 impl Eq for NoteAssets {}
 
+// NOTE ASSETS BUILDER
+// ================================================================================================
+
+/// A builder which collects the assets of a note one at a time.
+///
+/// Fungible assets issued by the same faucet are merged into a single asset, and adding a
+/// non-fungible asset which has already been added is rejected. Thus, the resulting list of assets
+/// satisfies the same invariants as the assets of an account vault.
+#[derive(Debug, Clone, Default)]
+pub struct NoteAssetsBuilder {
+    assets: Vec<Asset>,
+}
+
+impl NoteAssetsBuilder {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new [NoteAssetsBuilder] with no assets.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // MODIFIERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds the provided asset to this builder.
+    ///
+    /// If a fungible asset issued by the same faucet has already been added, the amount of the
+    /// provided asset is added to it.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The same non-fungible asset has already been added.
+    /// - The total amount of a fungible asset exceeds the maximum fungible asset amount.
+    pub fn add_asset(&mut self, asset: Asset) -> Result<(), NoteError> {
+        match self.assets.iter_mut().find(|a| a.is_same(&asset)) {
+            None => self.assets.push(asset),
+            Some(Asset::Fungible(existing)) => {
+                let Asset::Fungible(asset) = asset else {
+                    unreachable!("an asset is the same only as an asset of the same kind")
+                };
+                *existing = existing.add(asset).map_err(NoteError::InvalidAssetData)?;
+            },
+            Some(Asset::NonFungible(existing)) => {
+                return Err(NoteError::duplicate_non_fungible_asset(*existing));
+            },
+        }
+        Ok(())
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of distinct assets added so far.
+    pub fn num_assets(&self) -> usize {
+        self.assets.len()
+    }
+
+    // BUILDER
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the collected assets, which can be used to construct a note.
+    ///
+    /// # Errors
+    /// Returns an error if the collected assets cannot form [NoteAssets], e.g., if no assets were
+    /// added or an asset was not issued by a faucet of the matching type.
+    pub fn build(self) -> Result<Vec<Asset>, NoteError> {
+        NoteAssets::new(&self.assets)?;
+        Ok(self.assets)
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -165,3 +249,48 @@ impl Deserializable for NoteAssets {
         Self::new(&assets).map_err(|e| DeserializationError::InvalidValue(format!("{e:?}")))
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{NoteAssetsBuilder, NoteError};
+    use crate::{
+        accounts::{
+            AccountId, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+            ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN,
+        },
+        assets::{Asset, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
+        utils::collections::*,
+    };
+
+    #[test]
+    fn note_assets_builder_merges_fungible_assets() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+
+        let mut builder = NoteAssetsBuilder::new();
+        builder.add_asset(FungibleAsset::new(faucet_id, 100).unwrap().into()).unwrap();
+        builder.add_asset(FungibleAsset::new(faucet_id, 50).unwrap().into()).unwrap();
+        assert_eq!(builder.num_assets(), 1);
+
+        let expected: Asset = FungibleAsset::new(faucet_id, 150).unwrap().into();
+        assert_eq!(builder.build().unwrap(), vec![expected]);
+    }
+
+    #[test]
+    fn note_assets_builder_rejects_duplicate_non_fungible_assets() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+        let nft =
+            NonFungibleAsset::new(&NonFungibleAssetDetails::new(faucet_id, vec![1, 2, 3]).unwrap())
+                .unwrap();
+
+        let mut builder = NoteAssetsBuilder::new();
+        builder.add_asset(nft.into()).unwrap();
+        assert_eq!(builder.add_asset(nft.into()), Err(NoteError::DuplicateNonFungibleAsset(nft)));
+        assert_eq!(builder.build().unwrap(), vec![nft.into()]);
+
+        // an empty builder does not produce a valid asset list
+        assert_eq!(NoteAssetsBuilder::new().build(), Err(NoteError::EmptyAssetList));
+    }
+}
@@ -1,7 +1,7 @@
 use vm_processor::DeserializationError;
 
 use super::{
-    AccountId, ByteReader, ByteWriter, Deserializable, Felt, NoteError, Serializable, Word,
+    AccountId, ByteReader, ByteWriter, Deserializable, Felt, NoteError, NoteTag, Serializable, Word,
 };
 
 // NOTE METADATA
@@ -16,12 +16,12 @@ use super::{
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct NoteMetadata {
     sender: AccountId,
-    tag: Felt,
+    tag: NoteTag,
 }
 
 impl NoteMetadata {
     /// Returns a new [NoteMetadata] instantiated with the specified parameters.
-    pub fn new(sender: AccountId, tag: Felt) -> Self {
+    pub fn new(sender: AccountId, tag: NoteTag) -> Self {
         Self { sender, tag }
     }
 
@@ -31,7 +31,7 @@ impl NoteMetadata {
     }
 
     /// Returns the tag associated with the note.
-    pub fn tag(&self) -> Felt {
+    pub fn tag(&self) -> NoteTag {
         self.tag
     }
 }
@@ -45,7 +45,7 @@ impl From<NoteMetadata> for Word {
 impl From<&NoteMetadata> for Word {
     fn from(metadata: &NoteMetadata) -> Self {
         let mut elements = Word::default();
-        elements[0] = metadata.tag;
+        elements[0] = metadata.tag.into();
         elements[1] = metadata.sender.into();
         elements
     }
@@ -57,7 +57,7 @@ impl TryFrom<Word> for NoteMetadata {
     fn try_from(elements: Word) -> Result<Self, Self::Error> {
         Ok(Self {
             sender: elements[1].try_into().map_err(NoteError::NoteMetadataSenderInvalid)?,
-            tag: elements[0],
+            tag: elements[0].try_into()?,
         })
     }
 }
@@ -68,14 +68,16 @@ impl TryFrom<Word> for NoteMetadata {
 impl Serializable for NoteMetadata {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         self.sender.write_into(target);
-        self.tag.write_into(target);
+        self.tag.inner().write_into(target);
     }
 }
 
 impl Deserializable for NoteMetadata {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         let sender = AccountId::read_from(source)?;
-        let tag = Felt::read_from(source)?;
+        let tag = Felt::read_from(source)?
+            .try_into()
+            .map_err(|err: NoteError| DeserializationError::InvalidValue(err.to_string()))?;
 
         Ok(Self { sender, tag })
     }
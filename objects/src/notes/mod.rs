@@ -1,10 +1,16 @@
+#[cfg(feature = "std")]
+use crate::crypto::dsa::rpo_falcon512::KeyPair;
 use crate::{
     accounts::AccountId,
     assembly::{Assembler, AssemblyContext, ProgramAst},
-    assets::Asset,
-    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    assets::{Asset, FungibleAsset, NonFungibleAsset},
+    crypto::dsa::rpo_falcon512::{PublicKey, Signature},
+    utils::{
+        collections::*,
+        serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    },
     vm::CodeBlock,
-    Digest, Felt, Hasher, NoteError, Word, NOTE_TREE_DEPTH, WORD_SIZE, ZERO,
+    AssetError, Digest, Felt, Hasher, NoteError, Word, NOTE_TREE_DEPTH, WORD_SIZE, ZERO,
 };
 
 mod envelope;
@@ -19,6 +25,9 @@ pub use metadata::NoteMetadata;
 mod note_id;
 pub use note_id::NoteId;
 
+mod note_tag;
+pub use note_tag::NoteTag;
+
 mod nullifier;
 pub use nullifier::Nullifier;
 
@@ -26,10 +35,10 @@ mod origin;
 pub use origin::{NoteInclusionProof, NoteOrigin};
 
 mod script;
-pub use script::NoteScript;
+pub use script::{InputFieldKind, NoteScript};
 
 mod assets;
-pub use assets::NoteAssets;
+pub use assets::{NoteAssets, NoteAssetsBuilder};
 
 // CONSTANTS
 // ================================================================================================
@@ -85,10 +94,55 @@ pub struct Note {
     nullifier: Nullifier,
 }
 
-fn compute_recipient(serial_num: Word, script: &NoteScript, inputs: &NoteInputs) -> Digest {
+/// Returns the recipient of a note with the specified script root, serial number, and inputs
+/// commitment.
+///
+/// The recipient is computed as hash(hash(hash(serial_num, [0; 4]), script_root), inputs_hash).
+/// This allows computing the recipient of a note before the note itself is constructed, e.g., to
+/// reference the note from the script which creates it.
+pub fn build_recipient(script_root: Digest, serial_num: Word, inputs_hash: Digest) -> Digest {
     let serial_num_hash = Hasher::merge(&[serial_num.into(), Digest::default()]);
-    let merge_script = Hasher::merge(&[serial_num_hash, script.hash()]);
-    Hasher::merge(&[merge_script, inputs.commitment()])
+    let merge_script = Hasher::merge(&[serial_num_hash, script_root]);
+    Hasher::merge(&[merge_script, inputs_hash])
+}
+
+/// Returns the total assets carried by the provided notes.
+///
+/// Fungible assets issued by the same faucet are summed into a single asset, while non-fungible
+/// assets are collected individually. Fungible assets are returned first, ordered by faucet ID,
+/// followed by non-fungible assets in the order in which they appear in the notes.
+///
+/// # Errors
+/// Returns an error if:
+/// - The total amount of a fungible asset exceeds the maximum fungible asset amount.
+/// - The same non-fungible asset is carried by more than one note.
+pub fn aggregate_assets(notes: &[Note]) -> Result<Vec<Asset>, AssetError> {
+    let mut fungible = BTreeMap::<AccountId, FungibleAsset>::new();
+    let mut non_fungible = Vec::new();
+
+    for asset in notes.iter().flat_map(|note| note.assets().iter()) {
+        match asset {
+            Asset::Fungible(asset) => {
+                let total = match fungible.remove(&asset.faucet_id()) {
+                    Some(total) => total.add(*asset)?,
+                    None => *asset,
+                };
+                fungible.insert(asset.faucet_id(), total);
+            },
+            Asset::NonFungible(asset) => {
+                if non_fungible.contains(asset) {
+                    return Err(AssetError::duplicate_non_fungible_asset(*asset));
+                }
+                non_fungible.push(*asset);
+            },
+        }
+    }
+
+    Ok(fungible
+        .into_values()
+        .map(Asset::from)
+        .chain(non_fungible.into_iter().map(Asset::from))
+        .collect())
 }
 
 impl Note {
@@ -101,6 +155,7 @@ impl Note {
     /// - The number of inputs exceeds 16.
     /// - The number of provided assets exceeds 1000.
     /// - The list of assets contains duplicates.
+    /// - The tag is not a valid [NoteTag].
     pub fn new(
         script: NoteScript,
         inputs: &[Felt],
@@ -111,7 +166,7 @@ impl Note {
     ) -> Result<Self, NoteError> {
         let inputs = NoteInputs::new(inputs.to_vec())?;
         let assets = NoteAssets::new(assets)?;
-        let metadata = NoteMetadata::new(sender, tag);
+        let metadata = NoteMetadata::new(sender, tag.try_into()?);
 
         Ok(Self::from_parts(script, inputs, assets, serial_num, metadata))
     }
@@ -124,7 +179,7 @@ impl Note {
         serial_num: Word,
         metadata: NoteMetadata,
     ) -> Self {
-        let recipient = compute_recipient(serial_num, &script, &inputs);
+        let recipient = build_recipient(script.hash(), serial_num, inputs.commitment());
         let id = NoteId::new(recipient, assets.commitment());
         let nullifier =
             Nullifier::new(script.hash(), inputs.commitment(), assets.commitment(), serial_num);
@@ -158,6 +213,24 @@ impl Note {
         &self.assets
     }
 
+    /// Returns the fungible assets of this note, in the order in which they appear in the note.
+    pub fn fungible_assets(&self) -> Vec<FungibleAsset> {
+        self.assets.iter().filter_map(|asset| asset.as_fungible().copied()).collect()
+    }
+
+    /// Returns the non-fungible assets of this note, in the order in which they appear in the note.
+    pub fn non_fungible_assets(&self) -> Vec<NonFungibleAsset> {
+        self.assets
+            .iter()
+            .filter_map(|asset| asset.as_non_fungible().copied())
+            .collect()
+    }
+
+    /// Returns the IDs of all faucets which issued the assets of this note.
+    pub fn faucet_ids(&self) -> BTreeSet<AccountId> {
+        self.assets.iter().map(|asset| asset.faucet_id()).collect()
+    }
+
     /// Returns a serial number of this note.
     pub fn serial_num(&self) -> Word {
         self.serial_num
@@ -189,10 +262,74 @@ impl Note {
         Hasher::merge(&[self.id().inner(), Word::from(self.metadata()).into()])
     }
 
+    /// Returns the message which is signed to authenticate this note.
+    ///
+    /// The message commits to the sender, the inputs and the assets of the note, i.e., to the
+    /// data which is accessible to the note script, and is computed as:
+    ///
+    /// > hash(assets_commitment, hash(inputs_commitment, hash([0; 4], [sender, 0, 0, 0])))
+    pub fn signature_message(&self) -> Word {
+        let sender = [self.metadata.sender().into(), ZERO, ZERO, ZERO];
+        let sender_hash = Hasher::merge(&[Digest::default(), sender.into()]);
+        let inputs_hash = Hasher::merge(&[self.inputs.commitment(), sender_hash]);
+        Hasher::merge(&[self.assets.commitment(), inputs_hash]).into()
+    }
+
+    /// Signs this note with the provided key pair.
+    ///
+    /// The signature is computed over the [Note::signature_message()] and can be verified within
+    /// a note script via `miden::note::verify_signature`.
+    ///
+    /// # Errors
+    /// Returns an error if signature generation fails.
+    #[cfg(feature = "std")]
+    pub fn sign(&self, key_pair: &KeyPair) -> Result<Signature, NoteError> {
+        key_pair
+            .sign(self.signature_message())
+            .map_err(|err| NoteError::NoteSigningFailed(err.to_string()))
+    }
+
+    /// Returns true if the provided signature over this note was produced by the owner of the
+    /// specified public key.
+    pub fn verify_signature(&self, pub_key: PublicKey, signature: &Signature) -> bool {
+        pub_key.verify(self.signature_message(), signature)
+    }
+
     /// Returns the nullifier for this note.
     pub fn nullifier(&self) -> Nullifier {
         self.nullifier
     }
+
+    // TRANSFORMATIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Splits this note into a set of notes each carrying exactly one of the assets of this note.
+    ///
+    /// The resulting notes share the script, inputs, and metadata of this note and are returned in
+    /// the order of the assets in this note. The serial number of the note at position `i` is
+    /// derived as hash(serial_num, [i, 0, 0, 0]), where `serial_num` is the serial number of this
+    /// note. Thus, the serial numbers are deterministic, distinct from each other and from the
+    /// serial number of this note, and the original note owner can recompute them.
+    pub fn split_by_asset(&self) -> Vec<Note> {
+        self.assets
+            .iter()
+            .enumerate()
+            .map(|(index, asset)| {
+                let index_word = [Felt::from(index as u32), ZERO, ZERO, ZERO];
+                let serial_num = Hasher::merge(&[self.serial_num.into(), index_word.into()]);
+                let assets = NoteAssets::new(&[*asset])
+                    .expect("an asset of a valid note must form valid note assets");
+
+                Note::from_parts(
+                    self.script.clone(),
+                    self.inputs.clone(),
+                    assets,
+                    serial_num.into(),
+                    self.metadata,
+                )
+            })
+            .collect()
+    }
 }
 
 // SERIALIZATION
@@ -243,9 +380,191 @@ impl serde::Serialize for Note {
 #[cfg(feature = "serde")]
 impl<'de> serde::Deserialize<'de> for Note {
     fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        use crate::utils::collections::*;
-
         let bytes: Vec<u8> = <Vec<u8> as serde::Deserialize>::deserialize(deserializer)?;
         Self::read_from_bytes(&bytes).map_err(serde::de::Error::custom)
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        aggregate_assets, build_recipient, Assembler, AssetError, Note, NoteError, NoteInputs,
+        NoteScript, ProgramAst,
+    };
+    use crate::{
+        accounts::{
+            AccountId, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+            ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+        },
+        assets::{Asset, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
+        crypto::dsa::rpo_falcon512::KeyPair,
+        utils::collections::*,
+        Felt, MAX_INPUTS_PER_NOTE, ZERO,
+    };
+
+    #[test]
+    fn note_signature_verification() {
+        let assembler = Assembler::default();
+        let (script, _) =
+            NoteScript::new(ProgramAst::parse("begin push.1 drop end").unwrap(), &assembler)
+                .unwrap();
+        let sender =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let serial_num = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let assets = [FungibleAsset::new(faucet_id, 100).unwrap().into()];
+        let note = Note::new(script, &[Felt::new(5)], &assets, serial_num, sender, ZERO).unwrap();
+
+        let sender_key = KeyPair::new().unwrap();
+        let other_key = KeyPair::new().unwrap();
+
+        // a signature by the sender's key is valid for the sender's public key only
+        let signature = note.sign(&sender_key).unwrap();
+        assert!(note.verify_signature(sender_key.public_key(), &signature));
+        assert!(!note.verify_signature(other_key.public_key(), &signature));
+
+        // a signature by a different key is not valid for the sender's public key
+        let signature = note.sign(&other_key).unwrap();
+        assert!(!note.verify_signature(sender_key.public_key(), &signature));
+    }
+
+    #[test]
+    fn note_recipient_from_parts() {
+        let assembler = Assembler::default();
+        let (script, _) =
+            NoteScript::new(ProgramAst::parse("begin push.1 drop end").unwrap(), &assembler)
+                .unwrap();
+        let sender =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let serial_num = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let inputs = [Felt::new(5), Felt::new(6)];
+
+        let inputs_hash = NoteInputs::new(inputs.to_vec()).unwrap().commitment();
+        let recipient = build_recipient(script.hash(), serial_num, inputs_hash);
+
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+        let note = Note::new(script, &inputs, &[asset], serial_num, sender, ZERO).unwrap();
+        assert_eq!(recipient, note.recipient());
+    }
+
+    #[test]
+    fn note_rejects_asset_from_invalid_faucet() {
+        let assembler = Assembler::default();
+        let (script, _) =
+            NoteScript::new(ProgramAst::parse("begin push.1 drop end").unwrap(), &assembler)
+                .unwrap();
+        let sender =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let serial_num = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+
+        // a fungible asset whose faucet id is a regular account id
+        let asset = Asset::Fungible(FungibleAsset::new_unchecked([
+            Felt::new(100),
+            ZERO,
+            ZERO,
+            sender.into(),
+        ]));
+        let result = Note::new(script.clone(), &[], &[asset], serial_num, sender, ZERO);
+        assert!(matches!(result, Err(NoteError::InvalidAssetFaucet(id)) if id == sender));
+
+        // a fungible asset whose faucet id is a non-fungible faucet id
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+        let asset = Asset::Fungible(FungibleAsset::new_unchecked([
+            Felt::new(100),
+            ZERO,
+            ZERO,
+            faucet_id.into(),
+        ]));
+        let result = Note::new(script.clone(), &[], &[asset], serial_num, sender, ZERO);
+        assert!(matches!(result, Err(NoteError::InvalidAssetFaucet(id)) if id == faucet_id));
+
+        // a non-fungible asset whose faucet id is a regular account id
+        let asset = Asset::NonFungible(unsafe {
+            NonFungibleAsset::new_unchecked([Felt::new(1), sender.into(), Felt::new(2), ZERO])
+        });
+        let result = Note::new(script, &[], &[asset], serial_num, sender, ZERO);
+        assert!(matches!(result, Err(NoteError::InvalidAssetFaucet(id)) if id == sender));
+    }
+
+    #[test]
+    fn note_inputs_limit() {
+        let assembler = Assembler::default();
+        let (script, _) =
+            NoteScript::new(ProgramAst::parse("begin push.1 drop end").unwrap(), &assembler)
+                .unwrap();
+        let sender =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let serial_num = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+        // a note with exactly the maximum number of inputs is valid
+        let inputs = vec![Felt::new(1); MAX_INPUTS_PER_NOTE];
+        let note = Note::new(script.clone(), &inputs, &[asset], serial_num, sender, ZERO).unwrap();
+        assert_eq!(note.inputs().values().len(), MAX_INPUTS_PER_NOTE);
+
+        // one more input than the maximum is rejected
+        let inputs = vec![Felt::new(1); MAX_INPUTS_PER_NOTE + 1];
+        let result = Note::new(script, &inputs, &[asset], serial_num, sender, ZERO);
+        assert!(matches!(
+            result,
+            Err(NoteError::TooManyInputs(n)) if n == MAX_INPUTS_PER_NOTE + 1
+        ));
+    }
+
+    #[test]
+    fn note_assets_by_fungibility() {
+        let assembler = Assembler::default();
+        let (script, _) =
+            NoteScript::new(ProgramAst::parse("begin push.1 drop end").unwrap(), &assembler)
+                .unwrap();
+        let sender =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let serial_num = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let fungible_asset = FungibleAsset::new(faucet_id, 100).unwrap();
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+        let nft =
+            NonFungibleAsset::new(&NonFungibleAssetDetails::new(faucet_id, vec![1, 2, 3]).unwrap())
+                .unwrap();
+
+        let assets = [nft.into(), fungible_asset.into()];
+        let note = Note::new(script, &[], &assets, serial_num, sender, ZERO).unwrap();
+
+        assert_eq!(note.fungible_assets(), vec![fungible_asset]);
+        assert_eq!(note.non_fungible_assets(), vec![nft]);
+    }
+
+    #[test]
+    fn aggregate_assets_rejects_duplicate_non_fungible_assets() {
+        let assembler = Assembler::default();
+        let (script, _) =
+            NoteScript::new(ProgramAst::parse("begin push.1 drop end").unwrap(), &assembler)
+                .unwrap();
+        let sender =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+        let nft =
+            NonFungibleAsset::new(&NonFungibleAssetDetails::new(faucet_id, vec![1, 2, 3]).unwrap())
+                .unwrap();
+
+        let serial_num_1 = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let serial_num_2 = [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)];
+        let note_1 =
+            Note::new(script.clone(), &[], &[nft.into()], serial_num_1, sender, ZERO).unwrap();
+        let note_2 = Note::new(script, &[], &[nft.into()], serial_num_2, sender, ZERO).unwrap();
+
+        assert_eq!(aggregate_assets(core::slice::from_ref(&note_1)), Ok(vec![nft.into()]));
+        assert_eq!(
+            aggregate_assets(&[note_1, note_2]),
+            Err(AssetError::DuplicateNonFungibleAsset(nft))
+        );
+    }
+}
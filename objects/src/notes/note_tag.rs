@@ -0,0 +1,243 @@
+use core::fmt;
+
+use super::{AccountId, Felt, NoteError, NoteType};
+use crate::StarkField;
+
+// NOTE TAG
+// ================================================================================================
+
+/// A value attached to a note which the network uses to route the note to its recipient(s).
+///
+/// A tag addresses an account when the [NoteTag::ACCOUNT_PREFIX_BITS] most significant bits of
+/// the tag are equal to the [NoteTag::ACCOUNT_PREFIX_BITS] most significant bits of the account
+/// ID. The remaining low bits of the tag are not considered during routing, so a tag built from
+/// the full account ID (as done for P2ID notes) always matches that account.
+///
+/// Since the most significant bits of an account ID encode the account type and storage mode, a
+/// tag never matches accounts of a different type or storage mode than the one it was built for.
+///
+/// A tag is valid if it is a canonical field element and its prefix is not equal to
+/// [NoteTag::RESERVED_PREFIX]. The all-ones prefix is reserved because most of the values with this
+/// prefix are not representable by a field element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Felt", into = "Felt"))]
+pub struct NoteTag(Felt);
+
+impl NoteTag {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Number of the most significant bits of a tag which are compared against an account ID.
+    pub const ACCOUNT_PREFIX_BITS: u32 = 16;
+
+    /// Account prefix which is reserved and cannot be used by a tag.
+    pub const RESERVED_PREFIX: u64 = (1 << Self::ACCOUNT_PREFIX_BITS) - 1;
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a tag which addresses notes of the specified type to the specified account.
+    ///
+    /// The [NoteTag::ACCOUNT_PREFIX_BITS] most significant bits of the tag are set to the most
+    /// significant bits of the account ID, and thus, the tag always matches the account (see
+    /// [NoteTag::matches()]). The note type is encoded in the two least significant bits of the
+    /// tag as follows: 0 - off-chain, 1 - encrypted, 2 - local, 3 - network. All other bits are
+    /// set to zero, so unlike a tag built from the full account ID, this tag does not reveal the
+    /// account ID beyond its prefix.
+    ///
+    /// # Errors
+    /// Returns an error if the prefix of the account ID is equal to [NoteTag::RESERVED_PREFIX].
+    pub fn for_account(account_id: AccountId, note_type: NoteType) -> Result<Self, NoteError> {
+        let prefix_shift = u64::BITS - Self::ACCOUNT_PREFIX_BITS;
+        let account_prefix = u64::from(account_id) >> prefix_shift << prefix_shift;
+        let note_type_bits = match note_type {
+            NoteType::OffChain => 0,
+            NoteType::Encrypted => 1,
+            NoteType::Local => 2,
+            NoteType::Network => 3,
+        };
+
+        Self::try_from(Felt::new(account_prefix | note_type_bits))
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the [NoteTag::ACCOUNT_PREFIX_BITS] most significant bits of this tag.
+    pub fn account_prefix(&self) -> u64 {
+        self.0.as_int() >> (u64::BITS - Self::ACCOUNT_PREFIX_BITS)
+    }
+
+    /// Returns true if a note with this tag is addressed to the specified account.
+    ///
+    /// The match succeeds iff the [NoteTag::ACCOUNT_PREFIX_BITS] most significant bits of the tag
+    /// are equal to the [NoteTag::ACCOUNT_PREFIX_BITS] most significant bits of the account ID.
+    pub fn matches(&self, account_id: AccountId) -> bool {
+        let account_prefix = u64::from(account_id) >> (u64::BITS - Self::ACCOUNT_PREFIX_BITS);
+        self.account_prefix() == account_prefix
+    }
+
+    /// Returns the field element representing this tag.
+    pub fn inner(&self) -> Felt {
+        self.0
+    }
+}
+
+impl fmt::Display for NoteTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (account prefix 0x{:04x})", self.0.as_int(), self.account_prefix())
+    }
+}
+
+// CONVERSIONS
+// ================================================================================================
+
+impl TryFrom<Felt> for NoteTag {
+    type Error = NoteError;
+
+    fn try_from(value: Felt) -> Result<Self, Self::Error> {
+        let tag = Self(value);
+        if tag.account_prefix() == Self::RESERVED_PREFIX {
+            return Err(NoteError::InvalidNoteTag(value.as_int()));
+        }
+        Ok(tag)
+    }
+}
+
+impl TryFrom<u64> for NoteTag {
+    type Error = NoteError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        if value >= Felt::MODULUS {
+            return Err(NoteError::InvalidNoteTag(value));
+        }
+        Self::try_from(Felt::new(value))
+    }
+}
+
+impl TryFrom<AccountId> for NoteTag {
+    type Error = NoteError;
+
+    fn try_from(account_id: AccountId) -> Result<Self, Self::Error> {
+        Self::try_from(Felt::from(account_id))
+    }
+}
+
+impl From<NoteTag> for Felt {
+    fn from(tag: NoteTag) -> Self {
+        tag.0
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{AccountId, Felt, NoteError, NoteTag, NoteType};
+    use crate::{
+        accounts::{
+            AccountType, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
+            ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+        },
+        notes::NoteMetadata,
+        utils::serde::{Deserializable, Serializable},
+        Word,
+    };
+
+    #[test]
+    fn note_tag_matches_account_prefix() {
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+
+        // a tag built from the full account id matches the account
+        assert!(NoteTag::try_from(account_id).unwrap().matches(account_id));
+
+        // the low bits of the tag are ignored
+        let tag = NoteTag::try_from(u64::from(account_id) | 0xffff_ffff).unwrap();
+        assert!(tag.matches(account_id));
+    }
+
+    #[test]
+    fn note_tag_does_not_match_other_prefix() {
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let faucet_id_2 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2).unwrap();
+
+        assert!(!NoteTag::try_from(faucet_id).unwrap().matches(account_id));
+        assert!(!NoteTag::try_from(0u64).unwrap().matches(account_id));
+
+        // ids which only differ in the bits covered by the prefix do not match each other
+        assert!(!NoteTag::try_from(faucet_id).unwrap().matches(faucet_id_2));
+    }
+
+    #[test]
+    fn note_tag_for_account() {
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+
+        for note_type in
+            [NoteType::OffChain, NoteType::Encrypted, NoteType::Local, NoteType::Network]
+        {
+            let tag = NoteTag::for_account(account_id, note_type).unwrap();
+            assert!(tag.matches(account_id));
+            assert!(!tag.matches(faucet_id));
+            assert_eq!(NoteTag::try_from(tag.inner()), Ok(tag));
+        }
+
+        // only the account prefix and the note type are encoded in the tag
+        let tag = NoteTag::for_account(account_id, NoteType::Network).unwrap();
+        assert_eq!(tag.inner().as_int(), (u64::from(account_id) >> 48 << 48) | 3);
+    }
+
+    #[test]
+    fn note_tag_rejects_invalid_values() {
+        // values outside of the field are rejected
+        assert_eq!(NoteTag::try_from(u64::MAX), Err(NoteError::InvalidNoteTag(u64::MAX)));
+
+        // tags with the reserved prefix are rejected
+        let reserved = NoteTag::RESERVED_PREFIX << (u64::BITS - NoteTag::ACCOUNT_PREFIX_BITS);
+        assert_eq!(NoteTag::try_from(reserved), Err(NoteError::InvalidNoteTag(reserved)));
+        assert_eq!(
+            NoteTag::try_from(Felt::new(reserved)),
+            Err(NoteError::InvalidNoteTag(reserved))
+        );
+    }
+
+    #[test]
+    fn note_tag_accepts_valid_values() {
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let tag = NoteTag::try_from(Felt::from(account_id)).unwrap();
+        assert_eq!(tag, NoteTag::try_from(account_id).unwrap());
+
+        let tag = NoteTag::try_from(4u64).unwrap();
+        assert_eq!(tag.inner(), Felt::new(4));
+        assert_eq!(tag.to_string(), "4 (account prefix 0x0000)");
+    }
+
+    #[test]
+    fn note_tag_rejects_reserved_account_prefix() {
+        // an on-chain non-fungible faucet ID can start with the reserved prefix
+        let account_id = AccountId::try_from(0xffff_0000_0000_0001u64).unwrap();
+        assert_eq!(account_id.account_type(), AccountType::NonFungibleFaucet);
+
+        let id_value = u64::from(account_id);
+        assert_eq!(NoteTag::try_from(account_id), Err(NoteError::InvalidNoteTag(id_value)));
+        assert_eq!(
+            NoteTag::for_account(account_id, NoteType::OffChain),
+            Err(NoteError::InvalidNoteTag(0xffff_0000_0000_0000))
+        );
+
+        // a tag for the account can still be built from the low bits of the account ID, and
+        // metadata with such a tag round-trips
+        let tag = NoteTag::try_from(id_value & 0xffff_ffff).unwrap();
+        let metadata = NoteMetadata::new(account_id, tag);
+        assert_eq!(NoteMetadata::read_from_bytes(&metadata.to_bytes()).unwrap(), metadata);
+        assert_eq!(NoteMetadata::try_from(Word::from(metadata)).unwrap(), metadata);
+    }
+}
@@ -1,8 +1,18 @@
+use alloc::string::{String, ToString};
+
 use assembly::ast::AstSerdeOptions;
 
-use super::{Assembler, AssemblyContext, CodeBlock, Digest, NoteError, ProgramAst};
-use crate::utils::serde::{
-    ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
+use super::{
+    AccountId, Assembler, AssemblyContext, CodeBlock, Digest, Felt, FungibleAsset, NoteError,
+    NoteTag, ProgramAst,
+};
+use crate::{
+    utils::{
+        collections::*,
+        format,
+        serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    },
+    vm::{collect_call_branches, collect_syscall_branches},
 };
 
 // CONSTANTS
@@ -18,10 +28,17 @@ const CODE_SERDE_OPTIONS: AstSerdeOptions = AstSerdeOptions::new(true);
 ///
 /// A note's script represents a program which must be executed for a note to be consumed. As such
 /// it defines the rules and side effects of consuming a given note.
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// A note script may optionally declare the schema of the inputs it expects (see
+/// [NoteScript::with_input_schema()]). The schema is metadata only: it is not committed to by the
+/// script hash and is not enforced during note execution, but it allows tooling to present and
+/// validate note inputs. For the same reason, the schema is neither serialized nor considered when
+/// comparing note scripts.
+#[derive(Debug, Clone)]
 pub struct NoteScript {
     hash: Digest,
     code: ProgramAst,
+    input_schema: Option<Vec<InputFieldKind>>,
 }
 
 impl NoteScript {
@@ -37,7 +54,7 @@ impl NoteScript {
         let code_block = assembler
             .compile_in_context(&code, &mut AssemblyContext::for_program(Some(&code)))
             .map_err(NoteError::ScriptCompilationError)?;
-        Ok((Self { hash: code_block.hash(), code }, code_block))
+        Ok((Self::from_parts(code, code_block.hash()), code_block))
     }
 
     /// Returns a new [NoteScript] instantiated from the provided components.
@@ -45,7 +62,16 @@ impl NoteScript {
     /// **Note**: this function assumes that the specified hash results from the compilation of the
     /// provided program, but this is not checked.
     pub fn from_parts(code: ProgramAst, hash: Digest) -> Self {
-        Self { code, hash }
+        Self { code, hash, input_schema: None }
+    }
+
+    /// Returns this note script with the provided input schema attached.
+    ///
+    /// The schema lists the kinds of the inputs which the script expects, in the order in which
+    /// they are expected to be provided.
+    pub fn with_input_schema(mut self, input_schema: Vec<InputFieldKind>) -> Self {
+        self.input_schema = Some(input_schema);
+        self
     }
 
     // PUBLIC ACCESSORS
@@ -60,6 +86,208 @@ impl NoteScript {
     pub fn code(&self) -> &ProgramAst {
         &self.code
     }
+
+    /// Returns the input schema declared for this note script, or None if no schema was declared.
+    pub fn input_schema(&self) -> Option<&[InputFieldKind]> {
+        self.input_schema.as_deref()
+    }
+
+    /// Checks that the provided note inputs conform to the input schema of this note script.
+    ///
+    /// If no schema was declared for this script, any inputs are accepted.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The number of inputs is different from the number of fields in the schema.
+    /// - Any of the inputs is not a valid value for the kind of its field.
+    pub fn validate_inputs(&self, inputs: &[Felt]) -> Result<(), NoteError> {
+        let Some(input_schema) = &self.input_schema else {
+            return Ok(());
+        };
+
+        if inputs.len() != input_schema.len() {
+            return Err(NoteError::InputSchemaLengthMismatch {
+                expected: input_schema.len(),
+                actual: inputs.len(),
+            });
+        }
+
+        for (index, (kind, &input)) in input_schema.iter().zip(inputs).enumerate() {
+            if !kind.is_valid(input) {
+                return Err(NoteError::InvalidNoteInput { index, kind: *kind });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns MAST roots of all account procedures which this note script may invoke via `call`
+    /// across all of its execution branches.
+    ///
+    /// The script is compiled with the provided assembler to resolve the call targets; this
+    /// should be the same assembler which was used to compile the script originally.
+    ///
+    /// # Errors
+    /// Returns an error if the compilation of the script fails.
+    pub fn call_targets(&self, assembler: &Assembler) -> Result<Vec<Digest>, NoteError> {
+        let code_block = self.compile(assembler)?;
+
+        let call_targets = collect_call_branches(&code_block)
+            .into_iter()
+            .flatten()
+            .collect::<BTreeSet<_>>();
+
+        Ok(call_targets.into_iter().collect())
+    }
+
+    /// Returns MAST roots of all kernel procedures which this note script may invoke via
+    /// `syscall` across all of its execution branches.
+    ///
+    /// The script is compiled with the provided assembler to resolve the syscall targets; this
+    /// should be the same assembler which was used to compile the script originally.
+    ///
+    /// # Errors
+    /// Returns an error if the compilation of the script fails.
+    pub fn kernel_calls(&self, assembler: &Assembler) -> Result<Vec<Digest>, NoteError> {
+        let code_block = self.compile(assembler)?;
+
+        let kernel_calls = collect_syscall_branches(&code_block)
+            .into_iter()
+            .flatten()
+            .collect::<BTreeSet<_>>();
+
+        Ok(kernel_calls.into_iter().collect())
+    }
+
+    /// Returns a human-readable rendering of the MAST of this note script.
+    ///
+    /// Each node of the compiled program is printed on its own line, with nested nodes indented
+    /// under their parents. `call` and `syscall` nodes are annotated with the MAST root of the
+    /// invoked procedure, which makes it possible to see which account procedures the script
+    /// depends on.
+    ///
+    /// The script is compiled with the provided assembler; this should be the same assembler
+    /// which was used to compile the script originally.
+    ///
+    /// # Errors
+    /// Returns an error if the compilation of the script fails.
+    pub fn disassemble(&self, assembler: &Assembler) -> Result<String, NoteError> {
+        let code_block = self.compile(assembler)?;
+
+        let mut output = String::new();
+        disassemble_code_block(&code_block, 0, &mut output);
+        Ok(output)
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Compiles the code of this note script with the provided assembler.
+    fn compile(&self, assembler: &Assembler) -> Result<CodeBlock, NoteError> {
+        assembler
+            .compile_in_context(&self.code, &mut AssemblyContext::for_program(Some(&self.code)))
+            .map_err(NoteError::ScriptCompilationError)
+    }
+}
+
+impl PartialEq for NoteScript {
+    fn eq(&self, other: &Self) -> bool {
+        // the input schema is metadata only and is not a part of the script
+        self.hash == other.hash && self.code == other.code
+    }
+}
+
+impl Eq for NoteScript {}
+
+// INPUT FIELD KIND
+// ================================================================================================
+
+/// The kind of a single note input as declared in the input schema of a [NoteScript].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum InputFieldKind {
+    /// An arbitrary field element.
+    Felt = 0,
+    /// An account ID.
+    AccountId = 1,
+    /// An amount of a fungible asset.
+    Amount = 2,
+    /// A note tag.
+    Tag = 3,
+}
+
+impl InputFieldKind {
+    /// Returns true if the provided field element is a valid value for an input of this kind.
+    pub fn is_valid(&self, input: Felt) -> bool {
+        match self {
+            Self::Felt => true,
+            Self::AccountId => AccountId::try_from(input).is_ok(),
+            Self::Amount => input.as_int() <= FungibleAsset::MAX_AMOUNT,
+            Self::Tag => NoteTag::try_from(input).is_ok(),
+        }
+    }
+}
+
+impl TryFrom<u8> for InputFieldKind {
+    type Error = NoteError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Felt),
+            1 => Ok(Self::AccountId),
+            2 => Ok(Self::Amount),
+            3 => Ok(Self::Tag),
+            _ => Err(NoteError::InvalidInputFieldKind(value)),
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Appends a rendering of the provided code block to the output, indenting each line by the
+/// specified depth.
+fn disassemble_code_block(code_block: &CodeBlock, depth: usize, output: &mut String) {
+    let indent = "    ".repeat(depth);
+    match code_block {
+        CodeBlock::Join(block) => {
+            output.push_str(&format!("{indent}join\n"));
+            disassemble_code_block(block.first(), depth + 1, output);
+            disassemble_code_block(block.second(), depth + 1, output);
+            output.push_str(&format!("{indent}end\n"));
+        },
+        CodeBlock::Split(block) => {
+            output.push_str(&format!("{indent}if.true\n"));
+            disassemble_code_block(block.on_true(), depth + 1, output);
+            output.push_str(&format!("{indent}else\n"));
+            disassemble_code_block(block.on_false(), depth + 1, output);
+            output.push_str(&format!("{indent}end\n"));
+        },
+        CodeBlock::Loop(block) => {
+            output.push_str(&format!("{indent}while.true\n"));
+            disassemble_code_block(block.body(), depth + 1, output);
+            output.push_str(&format!("{indent}end\n"));
+        },
+        CodeBlock::Call(block) => {
+            let op = if block.is_syscall() { "syscall" } else { "call" };
+            output.push_str(&format!("{indent}{op}.{}\n", block.fn_hash()));
+        },
+        CodeBlock::Span(block) => {
+            let ops = block
+                .op_batches()
+                .iter()
+                .flat_map(|batch| batch.ops())
+                .map(|op| op.to_string())
+                .collect::<Vec<_>>();
+            output.push_str(&format!("{indent}span {}\n", ops.join(" ")));
+        },
+        CodeBlock::Proxy(block) => {
+            output.push_str(&format!("{indent}proxy.{}\n", block.hash()));
+        },
+        CodeBlock::Dyn(_) => {
+            output.push_str(&format!("{indent}dyn\n"));
+        },
+    }
 }
 
 // SERIALIZATION
@@ -80,3 +308,173 @@ impl Deserializable for NoteScript {
         Ok(Self::from_parts(code, hash))
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{Assembler, Felt, InputFieldKind, NoteError, NoteScript, ProgramAst};
+    use crate::{
+        accounts::{AccountCode, ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN},
+        assembly::ModuleAst,
+        utils::{
+            collections::*,
+            format,
+            serde::{Deserializable, Serializable},
+        },
+    };
+
+    #[test]
+    fn note_script_call_targets() {
+        let account_source = "
+            export.proc_1
+                push.1 push.2 add
+            end
+            export.proc_2
+                push.1 push.2 mul
+            end
+            export.proc_3
+                push.1 push.2 sub
+            end
+        ";
+        let assembler = Assembler::default();
+        let account_code =
+            AccountCode::new(ModuleAst::parse(account_source).unwrap(), &assembler).unwrap();
+        let procs = account_code.procedures().iter().map(|p| p.to_hex()).collect::<Vec<_>>();
+
+        let note_source = format!(
+            "begin
+                call.{proc_1}
+                if.true
+                    call.{proc_2}
+                else
+                    call.{proc_3}
+                    call.{proc_1}
+                end
+            end",
+            proc_1 = procs[0],
+            proc_2 = procs[1],
+            proc_3 = procs[2],
+        );
+        let (note_script, _) =
+            NoteScript::new(ProgramAst::parse(&note_source).unwrap(), &assembler).unwrap();
+
+        let mut expected = account_code.procedures().to_vec();
+        expected.sort();
+        assert_eq!(note_script.call_targets(&assembler).unwrap(), expected);
+    }
+
+    #[test]
+    fn note_script_kernel_calls() {
+        let kernel_source = "
+            export.kernel_proc_1
+                push.1 push.2 add
+            end
+            export.kernel_proc_2
+                push.1 push.2 mul
+            end
+        ";
+        let assembler = Assembler::default().with_kernel(kernel_source).unwrap();
+        let kernel_procs = assembler.kernel().proc_hashes().to_vec();
+
+        let account_source = "
+            export.proc_1
+                push.1 push.2 sub
+            end
+        ";
+        let account_code =
+            AccountCode::new(ModuleAst::parse(account_source).unwrap(), &assembler).unwrap();
+
+        let note_source = format!(
+            "begin
+                call.{proc_1}
+                if.true
+                    syscall.kernel_proc_1
+                else
+                    syscall.kernel_proc_2
+                end
+            end",
+            proc_1 = account_code.procedures()[0].to_hex(),
+        );
+        let (note_script, _) =
+            NoteScript::new(ProgramAst::parse(&note_source).unwrap(), &assembler).unwrap();
+
+        let mut expected = kernel_procs;
+        expected.sort();
+        assert_eq!(note_script.kernel_calls(&assembler).unwrap(), expected);
+
+        // kernel procedures are not reported as call targets and vice versa
+        assert_eq!(note_script.call_targets(&assembler).unwrap(), account_code.procedures());
+    }
+
+    #[test]
+    fn note_script_disassemble() {
+        let account_source = "
+            export.proc_1
+                push.1 push.2 add
+            end
+            export.proc_2
+                push.1 push.2 mul
+            end
+        ";
+        let assembler = Assembler::default();
+        let account_code =
+            AccountCode::new(ModuleAst::parse(account_source).unwrap(), &assembler).unwrap();
+        let procs = account_code.procedures().iter().map(|p| p.to_hex()).collect::<Vec<_>>();
+
+        let note_source = format!(
+            "begin
+                push.1
+                if.true
+                    call.{proc_1}
+                else
+                    call.{proc_2}
+                end
+            end",
+            proc_1 = procs[0],
+            proc_2 = procs[1],
+        );
+        let (note_script, _) =
+            NoteScript::new(ProgramAst::parse(&note_source).unwrap(), &assembler).unwrap();
+
+        let disassembly = note_script.disassemble(&assembler).unwrap();
+        assert!(disassembly.contains("if.true"));
+        for proc in procs {
+            assert!(disassembly.contains(&format!("call.{proc}")));
+        }
+    }
+
+    #[test]
+    fn note_script_validate_inputs() {
+        let assembler = Assembler::default();
+        let (note_script, _) =
+            NoteScript::new(ProgramAst::parse("begin push.1 drop end").unwrap(), &assembler)
+                .unwrap();
+
+        // without a schema any inputs are accepted
+        assert!(note_script.validate_inputs(&[Felt::new(1), Felt::new(2)]).is_ok());
+
+        let note_script = note_script.with_input_schema(vec![InputFieldKind::AccountId]);
+        let account_id = Felt::new(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN);
+        assert!(note_script.validate_inputs(&[account_id]).is_ok());
+
+        assert!(matches!(
+            note_script.validate_inputs(&[account_id, Felt::new(1)]),
+            Err(NoteError::InputSchemaLengthMismatch { expected: 1, actual: 2 })
+        ));
+        assert!(matches!(
+            note_script.validate_inputs(&[Felt::new(0)]),
+            Err(NoteError::InvalidNoteInput {
+                index: 0,
+                kind: InputFieldKind::AccountId
+            })
+        ));
+
+        // the schema is neither serialized nor compared
+        let bytes = note_script.to_bytes();
+        let deserialized = NoteScript::read_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.input_schema(), None);
+        assert_eq!(deserialized, note_script);
+    }
+}
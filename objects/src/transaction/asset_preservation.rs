@@ -0,0 +1,70 @@
+use crate::{
+    accounts::AccountId,
+    assets::{Asset, AssetVault},
+    notes::Note,
+    utils::collections::*,
+    AssetError,
+};
+
+// ASSET PRESERVATION
+// ================================================================================================
+
+/// Checks that the assets carried by the created notes can be covered by the assets of the
+/// consumed notes and the assets in the provided account vault.
+///
+/// Fungible assets are summed per faucet, while non-fungible assets are tracked individually. Any
+/// surplus of consumed assets is assumed to be moved into the account vault.
+///
+/// This check is performed on the host and is only a necessary condition for a transaction to
+/// preserve assets: the kernel additionally verifies that the account vault delta matches the
+/// difference between the consumed and the created assets.
+///
+/// # Errors
+/// Returns an error if:
+/// - The amount of a fungible asset in the created notes exceeds the amount of that asset in the
+///   consumed notes and the account vault.
+/// - A non-fungible asset in the created notes is present neither in the consumed notes nor in
+///   the account vault, or it is present in more than one created note.
+pub fn check_asset_preservation(
+    vault: &AssetVault,
+    consumed: &[Note],
+    created: &[Note],
+) -> Result<(), AssetError> {
+    let mut fungible = BTreeMap::<AccountId, u64>::new();
+    let mut non_fungible = Vec::new();
+
+    let available = vault
+        .assets()
+        .chain(consumed.iter().flat_map(|note| note.assets().iter().copied()));
+    for asset in available {
+        match asset {
+            Asset::Fungible(asset) => {
+                let amount = fungible.entry(asset.faucet_id()).or_default();
+                *amount = amount.saturating_add(asset.amount());
+            },
+            Asset::NonFungible(asset) => {
+                non_fungible.push(asset);
+            },
+        }
+    }
+
+    for asset in created.iter().flat_map(|note| note.assets().iter()) {
+        match asset {
+            Asset::Fungible(asset) => {
+                let amount = fungible.entry(asset.faucet_id()).or_default();
+                if *amount < asset.amount() {
+                    return Err(AssetError::asset_amount_not_sufficient(*amount, asset.amount()));
+                }
+                *amount -= asset.amount();
+            },
+            Asset::NonFungible(asset) => {
+                match non_fungible.iter().position(|available| available == asset) {
+                    Some(index) => non_fungible.swap_remove(index),
+                    None => return Err(AssetError::non_fungible_asset_not_available(*asset)),
+                };
+            },
+        }
+    }
+
+    Ok(())
+}
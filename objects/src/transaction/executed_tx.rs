@@ -1,10 +1,11 @@
 use core::cell::OnceCell;
 
 use super::{
-    Account, AccountDelta, AccountId, AccountStub, AdviceInputs, BlockHeader, InputNotes,
-    OutputNotes, Program, TransactionArgs, TransactionId, TransactionInputs, TransactionOutputs,
-    TransactionWitness,
+    Account, AccountDelta, AccountId, AccountStub, AdviceInputs, BlockHeader, Digest, Felt,
+    InputNote, InputNotes, OutputNotes, Program, TransactionArgs, TransactionId, TransactionInputs,
+    TransactionOutputs, TransactionWitness, Word,
 };
+use crate::notes::NoteId;
 
 // EXECUTED TRANSACTION
 // ================================================================================================
@@ -88,6 +89,19 @@ impl ExecutedTransaction {
         &self.tx_outputs.account
     }
 
+    /// Returns true if the nonce of the account was incremented by this transaction.
+    ///
+    /// Every transaction which changes the state of the account must increment its nonce, so a
+    /// state-changing transaction for which this returns false will be rejected.
+    pub fn nonce_incremented(&self) -> bool {
+        self.final_account().nonce().as_int() > self.initial_account().nonce().as_int()
+    }
+
+    /// Returns the difference between the final and the initial nonce of the account.
+    pub fn nonce_delta(&self) -> Felt {
+        self.final_account().nonce() - self.initial_account().nonce()
+    }
+
     /// Returns the notes consumed in this transaction.
     pub fn input_notes(&self) -> &InputNotes {
         self.tx_inputs.input_notes()
@@ -108,6 +122,28 @@ impl ExecutedTransaction {
         self.tx_inputs.block_header()
     }
 
+    /// Returns the commitments of the block against which the transaction was executed, as they
+    /// are laid out in the block data section of the transaction kernel memory.
+    ///
+    /// The kernel prologue loads these values from the advice provider and verifies that they
+    /// hash to the block hash provided as a public input, so the returned values are exactly the
+    /// ones the transaction was executed against.
+    pub fn block_commitments(&self) -> BlockCommitments {
+        self.block_header().into()
+    }
+
+    /// Returns the data of the consumed note at the specified index, as it is laid out in the
+    /// consumed note data section of the transaction kernel memory.
+    ///
+    /// The kernel prologue loads these values from the advice provider and verifies them against
+    /// the input notes commitment, so they are computed from the input notes of this transaction.
+    /// Returns None if the index is out of range.
+    pub fn consumed_note(&self, index: u32) -> Option<ConsumedNoteInfo> {
+        let input_notes = self.input_notes();
+        let index = index as usize;
+        (index < input_notes.num_notes()).then(|| input_notes.get_note(index).into())
+    }
+
     /// Returns a description of changes between the initial and final account states.
     pub fn account_delta(&self) -> &AccountDelta {
         &self.account_delta
@@ -140,6 +176,71 @@ impl ExecutedTransaction {
     }
 }
 
+// BLOCK COMMITMENTS
+// ================================================================================================
+
+/// Commitments of the block a transaction was executed against.
+///
+/// These values occupy the block data section of the transaction kernel memory:
+/// - prev_block_hash: the hash of the previous block.
+/// - chain_root: the root of the chain MMR.
+/// - state_root: the root of the account database.
+/// - note_root: the root of the note tree of the block.
+/// - block_num: the number of the block.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct BlockCommitments {
+    pub prev_block_hash: Digest,
+    pub chain_root: Digest,
+    pub state_root: Digest,
+    pub note_root: Digest,
+    pub block_num: u32,
+}
+
+impl From<&BlockHeader> for BlockCommitments {
+    fn from(header: &BlockHeader) -> Self {
+        Self {
+            prev_block_hash: header.prev_hash(),
+            chain_root: header.chain_root(),
+            state_root: header.account_root(),
+            note_root: header.note_root(),
+            block_num: header.block_num(),
+        }
+    }
+}
+
+// CONSUMED NOTE INFO
+// ================================================================================================
+
+/// Data of a note consumed by a transaction.
+///
+/// These values occupy the consumed note data section of the transaction kernel memory:
+/// - id: the ID of the note.
+/// - serial_num: the serial number of the note.
+/// - script_root: the MAST root of the note script.
+/// - inputs_hash: the commitment to the note inputs.
+/// - assets_hash: the commitment to the note assets.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ConsumedNoteInfo {
+    pub id: NoteId,
+    pub serial_num: Word,
+    pub script_root: Digest,
+    pub inputs_hash: Digest,
+    pub assets_hash: Digest,
+}
+
+impl From<&InputNote> for ConsumedNoteInfo {
+    fn from(input_note: &InputNote) -> Self {
+        let note = input_note.note();
+        Self {
+            id: note.id(),
+            serial_num: note.serial_num(),
+            script_root: note.script().hash(),
+            inputs_hash: note.inputs().commitment(),
+            assets_hash: note.assets().commitment(),
+        }
+    }
+}
+
 impl From<ExecutedTransaction> for TransactionWitness {
     fn from(tx: ExecutedTransaction) -> Self {
         let (_, _, tx_witness) = tx.into_parts();
@@ -34,6 +34,8 @@ impl TransactionInputs {
     /// Returns an error if:
     /// - For a new account, account seed is not provided or the provided seed is invalid.
     /// - For an existing account, account seed was provided.
+    /// - Any of the input notes does not have an inclusion proof, or the proof is not valid against
+    ///   the block referenced by it.
     pub fn new(
         account: Account,
         account_seed: Option<Word>,
@@ -70,7 +72,10 @@ impl TransactionInputs {
         // which were created in the current block we skip this check because their authentication
         // paths are derived implicitly
         for note in input_notes.iter() {
-            let note_block_num = note.origin().block_num;
+            let note_block_num = match note.origin() {
+                Some(origin) => origin.block_num,
+                None => return Err(TransactionInputError::InputNoteMissingProof(note.id())),
+            };
 
             let block_header = if note_block_num == block_num {
                 &block_header
@@ -251,6 +256,12 @@ impl<T: ToNullifier> InputNotes<T> {
         &self.notes[idx]
     }
 
+    /// Returns true if the notes are sorted by their nullifiers, i.e., if they are arranged in
+    /// the order produced by [canonical_note_order()].
+    pub fn is_canonical(&self) -> bool {
+        self.notes.windows(2).all(|pair| pair[0].nullifier() <= pair[1].nullifier())
+    }
+
     // ITERATORS
     // --------------------------------------------------------------------------------------------
 
@@ -317,6 +328,15 @@ impl<T: ToNullifier> Deserializable for InputNotes<T> {
 // HELPER FUNCTIONS
 // ------------------------------------------------------------------------------------------------
 
+/// Sorts the provided notes by their nullifiers.
+///
+/// The transaction kernel processes input notes in the order in which they are provided, and
+/// the input notes commitment depends on this order. Arranging notes in this canonical order
+/// ensures that the same set of notes always results in the same commitment.
+pub fn canonical_note_order<T: ToNullifier>(notes: &mut [T]) {
+    notes.sort_by_key(|note| note.nullifier());
+}
+
 /// Returns the commitment to the input notes represented by the specified nullifiers.
 ///
 /// For a non-empty list of notes, this is a sequential hash of all (nullifier, ZERO) pairs for
@@ -342,13 +362,21 @@ pub fn build_input_notes_commitment<T: ToNullifier>(notes: &[T]) -> Digest {
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct InputNote {
     note: Note,
-    proof: NoteInclusionProof,
+    proof: Option<NoteInclusionProof>,
 }
 
 impl InputNote {
     /// Returns a new instance of an [InputNote] with the specified note and proof.
     pub fn new(note: Note, proof: NoteInclusionProof) -> Self {
-        Self { note, proof }
+        Self { note, proof: Some(proof) }
+    }
+
+    /// Returns a new instance of an [InputNote] for a note whose inclusion in the chain has not
+    /// been proven yet (e.g., a note which was created locally and not yet included in a block).
+    ///
+    /// Such notes cannot be consumed by a transaction until their inclusion proof is available.
+    pub fn without_proof(note: Note) -> Self {
+        Self { note, proof: None }
     }
 
     /// Returns the ID of the note.
@@ -361,21 +389,34 @@ impl InputNote {
         &self.note
     }
 
-    /// Returns a reference to the inclusion proof of the note.
-    pub fn proof(&self) -> &NoteInclusionProof {
-        &self.proof
+    /// Returns true if the inclusion proof of the note is present.
+    pub fn has_proof(&self) -> bool {
+        self.proof.is_some()
+    }
+
+    /// Returns a reference to the inclusion proof of the note, or None if the inclusion of the
+    /// note has not been proven yet.
+    pub fn proof(&self) -> Option<&NoteInclusionProof> {
+        self.proof.as_ref()
     }
 
-    /// Returns a reference to the origin of the note.
-    pub fn origin(&self) -> &NoteOrigin {
-        self.proof.origin()
+    /// Returns a reference to the origin of the note, or None if the inclusion of the note has
+    /// not been proven yet.
+    pub fn origin(&self) -> Option<&NoteOrigin> {
+        self.proof.as_ref().map(|proof| proof.origin())
     }
 
     /// Returns true if this note belongs to the note tree of the specified block.
+    ///
+    /// Notes without an inclusion proof are never considered to belong to a block.
     fn is_in_block(&self, block_header: &BlockHeader) -> bool {
-        let note_index = self.origin().node_index.value();
+        let Some(proof) = &self.proof else {
+            return false;
+        };
+
+        let note_index = proof.origin().node_index.value();
         let note_hash = self.note.authentication_hash();
-        self.proof.note_path().verify(note_index, note_hash, &block_header.note_root())
+        proof.note_path().verify(note_index, note_hash, &block_header.note_root())
     }
 }
 
@@ -392,8 +433,63 @@ impl Serializable for InputNote {
 impl Deserializable for InputNote {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         let note = Note::read_from(source)?;
-        let proof = NoteInclusionProof::read_from(source)?;
+        let proof = <Option<NoteInclusionProof>>::read_from(source)?;
 
         Ok(Self { note, proof })
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{canonical_note_order, InputNotes, Nullifier, MAX_INPUT_NOTES_PER_TX};
+    use crate::{Digest, Felt, TransactionInputError};
+
+    #[test]
+    fn canonical_note_order_is_independent_of_input_order() {
+        let nullifiers: Vec<Nullifier> = (1..=3)
+            .map(|i| Digest::new([Felt::new(i), Felt::new(i + 1), Felt::new(i + 2), Felt::new(i)]))
+            .map(Nullifier::from)
+            .collect();
+
+        let mut ordering_1 = vec![nullifiers[2], nullifiers[0], nullifiers[1]];
+        let mut ordering_2 = vec![nullifiers[1], nullifiers[2], nullifiers[0]];
+        assert_ne!(
+            InputNotes::new(ordering_1.clone()).unwrap().commitment(),
+            InputNotes::new(ordering_2.clone()).unwrap().commitment()
+        );
+
+        canonical_note_order(&mut ordering_1);
+        canonical_note_order(&mut ordering_2);
+
+        let notes_1 = InputNotes::new(ordering_1).unwrap();
+        let notes_2 = InputNotes::new(ordering_2).unwrap();
+        assert!(notes_1.is_canonical());
+        assert!(notes_2.is_canonical());
+        assert_eq!(notes_1.commitment(), notes_2.commitment());
+    }
+
+    #[test]
+    fn input_notes_reject_too_many_notes() {
+        let nullifiers: Vec<Nullifier> = (0..=MAX_INPUT_NOTES_PER_TX as u64)
+            .map(|i| {
+                Nullifier::from(Digest::new([
+                    Felt::new(i),
+                    Felt::new(0),
+                    Felt::new(0),
+                    Felt::new(0),
+                ]))
+            })
+            .collect();
+
+        assert_eq!(
+            InputNotes::new(nullifiers).unwrap_err(),
+            TransactionInputError::TooManyInputNotes {
+                max: MAX_INPUT_NOTES_PER_TX,
+                actual: MAX_INPUT_NOTES_PER_TX + 1
+            }
+        );
+    }
+}
@@ -5,6 +5,7 @@ use super::{
     BlockHeader, Digest, Felt, Hasher, Word, WORD_SIZE, ZERO,
 };
 
+mod asset_preservation;
 mod chain_mmr;
 mod executed_tx;
 mod inputs;
@@ -15,12 +16,13 @@ mod transaction_id;
 mod tx_args;
 mod tx_witness;
 
+pub use asset_preservation::check_asset_preservation;
 pub use chain_mmr::ChainMmr;
-pub use executed_tx::ExecutedTransaction;
-pub use inputs::{InputNote, InputNotes, ToNullifier, TransactionInputs};
+pub use executed_tx::{BlockCommitments, ConsumedNoteInfo, ExecutedTransaction};
+pub use inputs::{canonical_note_order, InputNote, InputNotes, ToNullifier, TransactionInputs};
 pub use outputs::{OutputNote, OutputNotes, ToEnvelope, TransactionOutputs};
 pub use prepared_tx::PreparedTransaction;
 pub use proven_tx::ProvenTransaction;
 pub use transaction_id::TransactionId;
-pub use tx_args::{TransactionArgs, TransactionScript};
+pub use tx_args::{TransactionArgs, TransactionScript, TxScriptArgs};
 pub use tx_witness::TransactionWitness;
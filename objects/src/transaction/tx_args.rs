@@ -1,8 +1,10 @@
-use super::{Digest, Felt, Word};
+use alloc::string::{String, ToString};
+
+use super::{Digest, Felt, Hasher, Word};
 use crate::{
     assembly::{Assembler, AssemblyContext, ProgramAst},
     notes::NoteId,
-    utils::collections::*,
+    utils::{collections::*, format},
     vm::CodeBlock,
     TransactionScriptError,
 };
@@ -17,10 +19,13 @@ use crate::{
 ///   scripts have been executed..
 /// - Note arguments: data put onto the the stack right before a note script is executed. These
 ///   are different from note inputs, as the executing account can specify arbitrary note args.
+/// - Validity window: an inclusive range of block numbers; if set, the transaction can only be
+///   executed against a reference block within this range.
 #[derive(Clone, Debug, Default)]
 pub struct TransactionArgs {
     tx_script: Option<TransactionScript>,
     note_args: Option<BTreeMap<NoteId, Word>>,
+    validity_window: Option<(u32, u32)>,
 }
 
 impl TransactionArgs {
@@ -33,13 +38,18 @@ impl TransactionArgs {
         tx_script: Option<TransactionScript>,
         note_args: Option<BTreeMap<NoteId, Word>>,
     ) -> Self {
-        Self { tx_script, note_args }
+        Self {
+            tx_script,
+            note_args,
+            validity_window: None,
+        }
     }
 
     pub fn with_tx_script(tx_script: TransactionScript) -> Self {
         Self {
             tx_script: Some(tx_script),
             note_args: None,
+            validity_window: None,
         }
     }
 
@@ -47,9 +57,20 @@ impl TransactionArgs {
         Self {
             tx_script: None,
             note_args: Some(not_args),
+            validity_window: None,
         }
     }
 
+    /// Restricts the transaction to be executed against a reference block with a number between
+    /// `min_block` and `max_block` (both inclusive).
+    ///
+    /// If `min_block` is greater than `max_block`, the transaction cannot be executed against any
+    /// block.
+    pub fn with_validity_window(mut self, min_block: u32, max_block: u32) -> Self {
+        self.validity_window = Some((min_block, max_block));
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -67,6 +88,19 @@ impl TransactionArgs {
     pub fn get_note_args(&self, note_id: NoteId) -> Option<&Word> {
         self.note_args.as_ref().and_then(|map| map.get(&note_id))
     }
+
+    /// Returns the inclusive range of reference block numbers the transaction is valid against,
+    /// if any.
+    pub fn validity_window(&self) -> Option<(u32, u32)> {
+        self.validity_window
+    }
+
+    /// Returns true if the transaction can be executed against the reference block with the
+    /// specified number.
+    pub fn is_valid_at(&self, block_num: u32) -> bool {
+        self.validity_window
+            .map_or(true, |(min_block, max_block)| (min_block..=max_block).contains(&block_num))
+    }
 }
 
 // TRANSACTION SCRIPT
@@ -82,11 +116,14 @@ impl TransactionArgs {
 /// - [hash](TransactionScript::hash): the hash of the compiled transaction script.
 /// - [inputs](TransactionScript::inputs): a map of key, value inputs that are loaded into the
 ///   advice map such that the transaction script can access them.
+/// - [args](TransactionScript::args): typed arguments which are loaded onto the stack before the
+///   body of the script is executed.
 #[derive(Clone, Debug)]
 pub struct TransactionScript {
     code: ProgramAst,
     hash: Digest,
     inputs: BTreeMap<Digest, Vec<Felt>>,
+    args: TxScriptArgs,
 }
 
 impl TransactionScript {
@@ -111,11 +148,37 @@ impl TransactionScript {
                 code,
                 hash: code_block.hash(),
                 inputs: inputs.into_iter().map(|(k, v)| (k.into(), v)).collect(),
+                args: TxScriptArgs::default(),
             },
             code_block,
         ))
     }
 
+    /// Returns a new instance of a [TransactionScript] with the provided script, arguments and
+    /// inputs and the compiled script code block.
+    ///
+    /// The code which loads the arguments onto the stack (see [TxScriptArgs::load_code]) is
+    /// prepended to the body of the script, and the advice map entry carrying the arguments is
+    /// added to the script inputs. Thus, the script body starts executing with the stack in the
+    /// following state:
+    ///
+    /// [ARG_0, ARG_1, ..., ARG_n]
+    ///
+    /// # Errors
+    /// Returns an error if script compilation fails.
+    pub fn with_args<T: IntoIterator<Item = (Word, Vec<Felt>)>>(
+        code: ProgramAst,
+        args: TxScriptArgs,
+        inputs: T,
+        assembler: &Assembler,
+    ) -> Result<(Self, CodeBlock), TransactionScriptError> {
+        let code = args.prepend_to(code);
+        let inputs = args.clone().into_iter().chain(inputs);
+        let (mut tx_script, code_block) = Self::new(code, inputs, assembler)?;
+        tx_script.args = args;
+        Ok((tx_script, code_block))
+    }
+
     /// Returns a new instance of a [TransactionScript] instantiated from the provided components.
     ///
     /// Note: this constructor does not verify that a compiled code in fact results in the provided
@@ -129,6 +192,7 @@ impl TransactionScript {
             code,
             hash,
             inputs: inputs.into_iter().map(|(k, v)| (k.into(), v)).collect(),
+            args: TxScriptArgs::default(),
         })
     }
 
@@ -149,4 +213,197 @@ impl TransactionScript {
     pub fn inputs(&self) -> &BTreeMap<Digest, Vec<Felt>> {
         &self.inputs
     }
+
+    /// Returns a reference to the arguments loaded onto the stack before the script body is
+    /// executed.
+    pub fn args(&self) -> &TxScriptArgs {
+        &self.args
+    }
+}
+
+// TRANSACTION SCRIPT ARGUMENTS
+// ================================================================================================
+
+/// A builder for typed arguments passed to a transaction script.
+///
+/// Arguments are provided as a sequence of words and are delivered to the transaction script via
+/// the advice map. The entry is keyed by the [commitment](TxScriptArgs::commitment) to the
+/// arguments, and the code returned by [load_code](TxScriptArgs::load_code) moves the arguments
+/// from the advice map onto the operand stack with the following layout:
+///
+/// [ARG_0, ARG_1, ..., ARG_n]
+///
+/// Where ARG_0 is the first argument added to the builder, and each argument is laid out on the
+/// stack as if it were pushed via `push.{ARG_i}`.
+///
+/// The simplest way to use the arguments is via [TransactionScript::with_args], which injects the
+/// loading code into the script and adds the arguments to the script inputs. Alternatively, since
+/// [TxScriptArgs] converts into an iterator of advice map entries, it can be passed as the inputs
+/// of a transaction script directly, or combined with other inputs via
+/// `args.into_iter().chain(other_inputs)`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TxScriptArgs {
+    args: Vec<Word>,
+}
+
+impl TxScriptArgs {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new empty instance of [TxScriptArgs].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a new instance of [TxScriptArgs] with the provided argument appended to the end of
+    /// the argument list.
+    pub fn with_arg(mut self, arg: Word) -> Self {
+        self.args.push(arg);
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the arguments in the order in which they were added.
+    pub fn args(&self) -> &[Word] {
+        &self.args
+    }
+
+    /// Returns the number of arguments.
+    pub fn len(&self) -> usize {
+        self.args.len()
+    }
+
+    /// Returns true if no arguments have been added.
+    pub fn is_empty(&self) -> bool {
+        self.args.is_empty()
+    }
+
+    /// Returns the values stored in the advice map for these arguments.
+    ///
+    /// The arguments are stored in reverse order so that the first argument ends up at the top of
+    /// the stack once all arguments are loaded.
+    pub fn to_elements(&self) -> Vec<Felt> {
+        self.args.iter().rev().flat_map(|arg| arg.iter().copied()).collect()
+    }
+
+    /// Returns a commitment to the arguments which is used as the advice map key.
+    pub fn commitment(&self) -> Digest {
+        Hasher::hash_elements(&self.to_elements())
+    }
+
+    /// Returns the advice map entry which carries the arguments.
+    pub fn to_advice_map_entry(&self) -> (Word, Vec<Felt>) {
+        (self.commitment().into(), self.to_elements())
+    }
+
+    /// Returns the assembly code which loads the arguments from the advice map onto the stack.
+    ///
+    /// The code expects nothing on the stack and leaves it in the following state:
+    ///
+    /// Inputs: []
+    /// Outputs: [ARG_0, ARG_1, ..., ARG_n]
+    ///
+    /// An empty string is returned if no arguments have been added.
+    pub fn load_code(&self) -> String {
+        if self.args.is_empty() {
+            return String::new();
+        }
+
+        let key = Word::from(self.commitment())
+            .iter()
+            .map(|felt| felt.as_int().to_string())
+            .collect::<Vec<_>>()
+            .join(".");
+
+        // the first word overwrites the key; each subsequent word is loaded into a new word
+        let mut code = format!("push.{key} adv.push_mapval adv_loadw");
+        for _ in 1..self.args.len() {
+            code.push_str(" padw adv_loadw");
+        }
+        code
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the provided program with the code loading the arguments prepended to its body.
+    fn prepend_to(&self, program: ProgramAst) -> ProgramAst {
+        if self.args.is_empty() {
+            return program;
+        }
+
+        let load_program = ProgramAst::parse(&format!("begin {} end", self.load_code()))
+            .expect("argument loading code is well formed");
+        let (_, mut body) = load_program.into_parts();
+
+        let import_info = program.import_info().clone();
+        let (procedures, program_body) = program.into_parts();
+        body.extend(program_body);
+
+        ProgramAst::new(body, procedures)
+            .expect("prepending instructions does not invalidate the program")
+            .with_import_info(import_info)
+    }
+}
+
+impl IntoIterator for TxScriptArgs {
+    type Item = (Word, Vec<Felt>);
+    type IntoIter = core::option::IntoIter<(Word, Vec<Felt>)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (!self.is_empty()).then(|| self.to_advice_map_entry()).into_iter()
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{Hasher, TxScriptArgs, Word};
+    use crate::{utils::collections::*, Felt};
+
+    fn word(start: u64) -> Word {
+        [
+            Felt::new(start),
+            Felt::new(start + 1),
+            Felt::new(start + 2),
+            Felt::new(start + 3),
+        ]
+    }
+
+    #[test]
+    fn tx_script_args_empty() {
+        let args = TxScriptArgs::new();
+        assert!(args.is_empty());
+        assert_eq!(args.len(), 0);
+        assert!(args.load_code().is_empty());
+        assert_eq!(args.into_iter().count(), 0);
+    }
+
+    #[test]
+    fn tx_script_args_single() {
+        let args = TxScriptArgs::new().with_arg(word(1));
+        assert_eq!(args.to_elements(), word(1).to_vec());
+
+        let code = args.load_code();
+        assert!(code.ends_with("adv.push_mapval adv_loadw"));
+        assert!(!code.contains("padw"));
+    }
+
+    #[test]
+    fn tx_script_args_elements_are_reversed() {
+        let args = TxScriptArgs::new().with_arg(word(1)).with_arg(word(5)).with_arg(word(9));
+        assert_eq!(args.len(), 3);
+
+        let expected = [word(9), word(5), word(1)].concat();
+        assert_eq!(args.to_elements(), expected);
+        assert_eq!(args.commitment(), Hasher::hash_elements(&expected));
+        assert_eq!(args.load_code().matches("padw adv_loadw").count(), 2);
+
+        let entries = args.clone().into_iter().collect::<Vec<_>>();
+        assert_eq!(entries, vec![(args.commitment().into(), expected)]);
+    }
 }
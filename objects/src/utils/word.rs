@@ -0,0 +1,45 @@
+use crate::{Felt, Word};
+
+// WORD CONVERSIONS
+// ================================================================================================
+
+/// Returns a [Word] built from the provided integers.
+///
+/// Integers which are not smaller than the field modulus are reduced modulo the field modulus.
+pub fn word_from_u64s(values: [u64; 4]) -> Word {
+    values.map(Felt::new)
+}
+
+/// Returns the canonical integer representations of the elements of the provided [Word].
+pub fn word_to_u64s(word: Word) -> [u64; 4] {
+    word.map(|felt| felt.as_int())
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{word_from_u64s, word_to_u64s};
+    use crate::{Digest, Felt, StarkField, Word, ONE, ZERO};
+
+    #[test]
+    fn word_u64s_round_trip() {
+        let max = Felt::MODULUS - 1;
+        for values in [[0, 0, 0, 0], [0, 1, 2, 3], [max, max, max, max], [max, 0, 1, max]] {
+            assert_eq!(word_to_u64s(word_from_u64s(values)), values);
+        }
+
+        assert_eq!(word_from_u64s([0, 1, 2, 3]), [ZERO, ONE, Felt::new(2), Felt::new(3)]);
+
+        // words convert to and from digests without changing their elements
+        let word = word_from_u64s([max, 0, 1, max]);
+        assert_eq!(Word::from(Digest::from(word)), word);
+    }
+
+    #[test]
+    fn word_from_u64s_reduces_values() {
+        let word = word_from_u64s([Felt::MODULUS, Felt::MODULUS + 1, u64::MAX, 0]);
+        assert_eq!(word_to_u64s(word), [0, 1, u64::MAX - Felt::MODULUS, 0]);
+    }
+}
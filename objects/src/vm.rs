@@ -0,0 +1,112 @@
+pub use miden_verifier::ExecutionProof;
+pub use vm_core::{code_blocks::CodeBlock, Program, ProgramInfo};
+pub use vm_processor::{AdviceInputs, AdviceMap, StackInputs, StackOutputs};
+
+use crate::{
+    utils::{collections::*, vec},
+    Digest,
+};
+
+// CALL BRANCHES
+// ================================================================================================
+
+/// Collects call branches by recursively traversing through program execution branches and
+/// accumulating call targets.
+///
+/// Each of the returned vectors contains the MAST roots of the procedures invoked via `call` in a
+/// single execution branch of the program. Syscalls are not included.
+///
+/// Proxy blocks are counted as call targets: the body of a proxied block is known only by its
+/// MAST root, and thus, the proxy is assumed to stand in for a called procedure. This makes sure
+/// that interface verification does not pass vacuously for programs with pruned branches.
+pub fn collect_call_branches(code_block: &CodeBlock) -> Vec<Vec<Digest>> {
+    let mut branches = vec![vec![]];
+    recursively_collect_call_branches(code_block, &mut branches, false);
+    branches
+}
+
+/// Collects syscall branches by recursively traversing through program execution branches and
+/// accumulating syscall targets.
+///
+/// Each of the returned vectors contains the MAST roots of the kernel procedures invoked via
+/// `syscall` in a single execution branch of the program. Regular calls are not included.
+pub fn collect_syscall_branches(code_block: &CodeBlock) -> Vec<Vec<Digest>> {
+    let mut branches = vec![vec![]];
+    recursively_collect_call_branches(code_block, &mut branches, true);
+    branches
+}
+
+/// Generates a list of calls invoked in each execution branch of the provided code block.
+///
+/// If `syscalls` is true, only the targets of syscalls are collected; otherwise, only the targets
+/// of regular calls are collected.
+fn recursively_collect_call_branches(
+    code_block: &CodeBlock,
+    branches: &mut Vec<Vec<Digest>>,
+    syscalls: bool,
+) {
+    match code_block {
+        CodeBlock::Join(block) => {
+            recursively_collect_call_branches(block.first(), branches, syscalls);
+            recursively_collect_call_branches(block.second(), branches, syscalls);
+        },
+        CodeBlock::Split(block) => {
+            let current_len = branches.last().expect("at least one execution branch").len();
+            recursively_collect_call_branches(block.on_false(), branches, syscalls);
+
+            // If the previous branch had additional calls we need to create a new branch
+            if branches.last().expect("at least one execution branch").len() > current_len {
+                branches.push(
+                    branches.last().expect("at least one execution branch")[..current_len].to_vec(),
+                );
+            }
+
+            recursively_collect_call_branches(block.on_true(), branches, syscalls);
+        },
+        CodeBlock::Loop(block) => {
+            recursively_collect_call_branches(block.body(), branches, syscalls);
+        },
+        CodeBlock::Call(block) => {
+            if block.is_syscall() != syscalls {
+                return;
+            }
+
+            branches
+                .last_mut()
+                .expect("at least one execution branch")
+                .push(block.fn_hash());
+        },
+        CodeBlock::Proxy(block) => {
+            if syscalls {
+                return;
+            }
+
+            branches.last_mut().expect("at least one execution branch").push(block.hash());
+        },
+        CodeBlock::Span(_) => {},
+        CodeBlock::Dyn(_) => {},
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{collect_call_branches, collect_syscall_branches, CodeBlock};
+    use crate::{Digest, Felt};
+
+    #[test]
+    fn call_branches_include_proxies() {
+        let call_target: Digest = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)].into();
+        let proxy_target: Digest = [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)].into();
+
+        let code_block = CodeBlock::new_join([
+            CodeBlock::new_call(call_target),
+            CodeBlock::new_proxy(proxy_target),
+        ]);
+
+        assert_eq!(collect_call_branches(&code_block), vec![vec![call_target, proxy_target]]);
+        assert_eq!(collect_syscall_branches(&code_block), vec![Vec::<Digest>::new()]);
+    }
+}